@@ -131,4 +131,31 @@ mod biguint_benchmarks {
             LocalBigUint::fac(2727);
         });
     }
-}
\ No newline at end of file
+
+    /// Two million digits, so one million buckets, with no long runs of zeros.
+    fn million_bucket_digits() -> String {
+        (0..2_000_000_u64).map(|i| char::from(b'1' + (i * 7 % 9) as u8)).collect()
+    }
+
+    /// Run once with `cargo bench mul_1m` and once with `cargo bench --features parallel mul_1m`
+    /// to see how the multi-modular product scales across threads. Each iteration takes
+    /// seconds, so expect the benchmark itself to take several minutes.
+    #[bench]
+    fn bench_mul_1m_buckets_local(b: &mut Bencher) {
+        let lhs = LocalBigUint::new(&million_bucket_digits());
+        let rhs = lhs.clone();
+        b.iter(|| &lhs * &rhs);
+    }
+
+    #[bench]
+    fn bench_mul_100k_digits_ntt_local(b: &mut Bencher) {
+        let lhs = LocalBigUint::new(&"9".repeat(100_000));
+        b.iter(|| &lhs * &lhs);
+    }
+
+    #[bench]
+    fn bench_mul_100k_digits_fft_f64_local(b: &mut Bencher) {
+        let lhs = LocalBigUint::new(&"9".repeat(100_000));
+        b.iter(|| lhs.mul_fft_f64(&lhs));
+    }
+}
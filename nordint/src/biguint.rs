@@ -1,18 +1,41 @@
 // author:  Erik Nordin
 // created: 07/14/2018
-// updated: 08/15/2018
+// updated: 10/18/2026
 // version: 0.1.0
 // contact: aeketn@gmail.com
 
-use ParseBigIntError;
-use std::cmp::{min, max};
+use {NttError, ParseBigIntError};
+use fft;
+use pow_mod::sliding_window;
+use ntt::fft_convolution;
+use prime;
+use std::cmp::{max, min, Ordering};
 use std::convert::From;
-use std::ops::{AddAssign, Mul, MulAssign, Rem};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{AddAssign, Mul, MulAssign, Sub, SubAssign};
 use std::str::{from_utf8, FromStr};
 use std::vec::Vec;
 
 const BUCKET_CAP: i64 = 100; 
-const DIGITS_PER_BUCKET: usize = 2;
+pub(crate) const DIGITS_PER_BUCKET: usize = 2;
+
+/// The most digits `pow_big` will produce. Squaring anything larger would need
+/// a multi-modular convolution longer than `2^23` points.
+const MAX_POW_DIGITS: f64 = 16_000_000.0;
+
+/// When either operand of a product has at most this many buckets,
+/// schoolbook multiplication is cheaper than a transform.
+const SCHOOLBOOK_BUCKETS: usize = 40;
+
+/// Primes used to cheaply rule out most composites before running
+/// the more expensive probable-prime tests on a `BigUint`.
+const SMALL_PRIMES: [i64; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Seeds the xorshift sequence that `is_probable_prime` draws its extra witnesses from.
+const WITNESS_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
 
 /// An unbounded, unsigned integer.
 ///
@@ -30,7 +53,7 @@ const DIGITS_PER_BUCKET: usize = 2;
 /// let number = BigUint::new("123,000,000,000,000,004,560");
 /// assert_eq!(format!("{:?}", number), "BigUint { buckets: [60, 45, 0, 0, 0, 0, 0, 0, 0, 23, 1] }");
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug)]
 pub struct BigUint {
     buckets: Vec<i64>,
 }
@@ -83,7 +106,7 @@ impl BigUint {
             BigUint::from_str(
                 &num_as_str
                     .chars()
-                    .filter(|character| character.is_digit(10))
+                    .filter(|character| character.is_ascii_digit())
                     .collect::<String>(),
             ).unwrap()
         }
@@ -97,7 +120,9 @@ impl BigUint {
 
     /// # Description
     ///
-    /// Calculates the traditional Fibonacci sequence up to the nth element.BigUint
+    /// Calculates the nth element of the traditional Fibonacci sequence.
+    /// Uses the fast-doubling identities of `fib_pair`, so only about
+    /// `3 log2(n)` multiplications are needed. As always, `fib(0)` is empty.
     ///
     /// # Example
     /// ```
@@ -107,10 +132,108 @@ impl BigUint {
     /// // 1, 1, (1+1)=2, (1+2)=3, (2+3)=5, (3+5)=8
     /// ```
     pub fn fib(n: usize) -> BigUint {
-        BigUint::fib_generic(BigUint::one(), BigUint::one(), n)
+        if 0 == n {
+            return BigUint::empty();
+        }
+        BigUint::fib_pair(n).0
+    }
+
+    /// Calculates the pair of Fibonacci numbers `(F(n), F(n + 1))`, where `F(0) = 0`.
+    ///
+    /// Walks the bits of `n` from the top with the fast-doubling identities
+    /// `F(2k) = F(k) (2 F(k + 1) - F(k))` and `F(2k + 1) = F(k)^2 + F(k + 1)^2`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!((BigUint::new("55"), BigUint::new("89")), BigUint::fib_pair(10));
+    /// assert_eq!((BigUint::zero(), BigUint::one()), BigUint::fib_pair(0));
+    /// ```
+    pub fn fib_pair(n: usize) -> (BigUint, BigUint) {
+        let mut current = BigUint::zero();
+        let mut next = BigUint::one();
+        for bit in (0..usize::BITS - n.leading_zeros()).rev() {
+            let mut doubled = next.clone();
+            doubled *= 2_u32;
+            let even = &current * &(&doubled - &current);
+            let mut odd = &current * &current;
+            odd += &(&next * &next);
+            if 1 == (n >> bit) & 1 {
+                current = odd;
+                next = even;
+                next += &current;
+            } else {
+                current = even;
+                next = odd;
+            }
+        }
+        (current, next)
     }
 
-    /// Calculates a generic Fibonacci sequence up to the nth element, provided two starting values.
+    /// Calculates the nth Lucas number, where `L(0) = 2` and `L(1) = 1`,
+    /// from `L(n) = 2 F(n + 1) - F(n)`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("123"), BigUint::lucas(10));
+    /// ```
+    pub fn lucas(n: usize) -> BigUint {
+        let (current, mut next) = BigUint::fib_pair(n);
+        next *= 2_u32;
+        &next - &current
+    }
+
+    /// Calculates the Lucas sequences `(U(n), V(n))` modulo `modulus`, where
+    /// `U(0) = 0`, `U(1) = 1`, `V(0) = 2`, `V(1) = P` and both follow
+    /// `X(k + 1) = P X(k) - Q X(k - 1)`.
+    ///
+    /// Raises the matrix `[[P, -Q], [1, 0]]` to the nth power, whose left column is
+    /// `(U(n + 1), U(n))`, and then takes `V(n) = 2 U(n + 1) - P U(n)`. Nothing is
+    /// halved along the way, so any modulus will do, even or odd.
+    ///
+    /// # Panics
+    /// Panics if the modulus is zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// // P = 1, Q = -1 gives the Fibonacci and Lucas numbers.
+    /// let modulus = BigUint::new("1,000");
+    /// let (u, v) = BigUint::lucas_sequence(1, -1, &BigUint::new("10"), &modulus);
+    /// assert_eq!((BigUint::new("55"), BigUint::new("123")), (u, v));
+    /// // P = 3, Q = 2 gives U(n) = 2^n - 1 and V(n) = 2^n + 1.
+    /// let (u, v) = BigUint::lucas_sequence(3, 2, &BigUint::new("20"), &modulus);
+    /// assert_eq!((BigUint::new("575"), BigUint::new("577")), (u, v));
+    /// ```
+    pub fn lucas_sequence(p: i64, q: i64, n: &BigUint, modulus: &BigUint) -> (BigUint, BigUint) {
+        assert!(!modulus.is_zero(), "modulus must be non-zero");
+        let to_residue = |value: i64| {
+            let magnitude = rem(&BigUint::from(value.unsigned_abs()), modulus);
+            if 0 <= value || magnitude.is_zero() {
+                magnitude
+            } else {
+                modulus - &magnitude
+            }
+        };
+        let reduce = |value: BigUint| rem(&value, modulus);
+        let matrix = [[to_residue(p), to_residue(-q)], [reduce(BigUint::one()), BigUint::zero()]];
+        let power = matrix_pow(matrix, &n.to_bits(), &reduce);
+        let [[u_next, _], [u, _]] = power;
+        let mut v = mul_mod(&to_residue(-p), &u, modulus);
+        v += &u_next;
+        v += &u_next;
+        (u, reduce(v))
+    }
+
+    /// Calculates the nth element of a generic Fibonacci sequence, provided two starting values.
+    ///
+    /// Since `G(n) = G(1) F(n - 2) + G(2) F(n - 1)`, the Fibonacci numbers are read off
+    /// the matrix `[[1, 1], [1, 0]]^(n - 2) = [[F(n - 1), F(n - 2)], [F(n - 2), F(n - 3)]]`,
+    /// which is found by repeated squaring.
     ///
     /// # Example
     /// ```
@@ -121,39 +244,654 @@ impl BigUint {
     /// assert_eq!(BigUint::new("28"), BigUint::fib_generic(first, second, 5));
     /// // 5,   6,   (5+6)=11, (6+11)=17,  (11+17)=28
     /// ```
-    pub fn fib_generic(mut first: BigUint, mut second: BigUint, n: usize) -> BigUint {
+    pub fn fib_generic(first: BigUint, second: BigUint, n: usize) -> BigUint {
         match n {
             0 => BigUint::empty(),
             1 => first,
-            2 => second,
             _ => {
-                for i in 3..=n {
-                    if 1 == i & 1 {
-                        first += &second;
-                    } else {
-                        second += &first;
-                    }
+                let matrix = [[BigUint::one(), BigUint::one()], [BigUint::one(), BigUint::zero()]];
+                let bits = (0..usize::BITS - (n - 2).leading_zeros())
+                    .map(|bit| 1 == ((n - 2) >> bit) & 1)
+                    .collect::<Vec<bool>>();
+                let [[f_1, f_2], _] = matrix_pow(matrix, &bits, &|value| value);
+                let mut result = &second * &f_1;
+                result += &(&first * &f_2);
+                result
+            }
+        }
+    }
+
+    /// Raises the number to a power by repeated squaring, so only about
+    /// `2 log2(exponent)` multiplications go through the fast multiplier.
+    ///
+    /// # Panics
+    /// Panics if the number is empty, or like the `*` operator if the result
+    /// is too large to multiply; see `pow_big` for a checked version.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("1,048,576"), BigUint::new("2").pow(20));
+    /// assert_eq!(BigUint::one(), BigUint::new("12345").pow(0));
+    /// ```
+    pub fn pow(&self, exponent: u32) -> BigUint {
+        assert!(!self.buckets.is_empty(), "cannot raise an empty BigUint to a power");
+        let mut result = BigUint::one();
+        for bit in (0..32 - exponent.leading_zeros()).rev() {
+            result = &result * &result;
+            if 1 == (exponent >> bit) & 1 {
+                result = &result * self;
+            }
+        }
+        result
+    }
+
+    /// Raises the number to a power given as a `BigUint`.
+    ///
+    /// Returns `None` instead of running out of memory, or panicking in the
+    /// multiplier, when the result would have more than sixteen million digits.
+    /// Zero and one can be raised to any power.
+    ///
+    /// # Panics
+    /// Panics if the number is empty.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let three = BigUint::new("3");
+    /// assert_eq!(Some(BigUint::new("59,049")), three.pow_big(&BigUint::new("10")));
+    /// assert_eq!(None, three.pow_big(&BigUint::new("1,000,000,000")));
+    /// assert_eq!(Some(BigUint::one()), BigUint::one().pow_big(&BigUint::new("1,000,000,000")));
+    /// ```
+    pub fn pow_big(&self, exponent: &BigUint) -> Option<BigUint> {
+        assert!(!self.buckets.is_empty(), "cannot raise an empty BigUint to a power");
+        if self.is_zero() || BigUint::one() == self.trimmed() {
+            return Some(if exponent.is_zero() { BigUint::one() } else { self.clone() });
+        }
+        let exponent = exponent.to_u64()?;
+        if exponent as f64 * self.log10() >= MAX_POW_DIGITS {
+            return None;
+        }
+        // The guard leaves the exponent far below u32::MAX, since the base is at least 2.
+        Some(self.pow(exponent as u32))
+    }
+
+    /// Creates `10^n` directly in base 100: `n / 2` zero buckets below a 1 or a 10.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("1000"), BigUint::pow_of_ten(3));
+    /// assert_eq!(BigUint::new("10").pow(25), BigUint::pow_of_ten(25));
+    /// ```
+    pub fn pow_of_ten(n: usize) -> BigUint {
+        let mut buckets = vec![0; n / DIGITS_PER_BUCKET];
+        buckets.push(if n.is_multiple_of(DIGITS_PER_BUCKET) { 1 } else { 10 });
+        BigUint { buckets }
+    }
+
+    /// Calculates the floor of the square root.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("1,414,213,562"), BigUint::new("2,000,000,000,000,000,000").isqrt());
+    /// ```
+    pub fn isqrt(&self) -> BigUint {
+        self.nth_root(2)
+    }
+
+    /// Calculates the floor of the square root and the remainder `self - root^2`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!((BigUint::new("12"), BigUint::new("6")), BigUint::new("150").sqrt_rem());
+    /// ```
+    pub fn sqrt_rem(&self) -> (BigUint, BigUint) {
+        let root = self.isqrt();
+        let square = BigUint::from_buckets(mul_slices(&root.buckets, &root.buckets));
+        let remainder = &self.trimmed() - &square;
+        (root, remainder)
+    }
+
+    /// Calculates the floor of the `k`th root with Newton's iteration,
+    /// `x' = ((k - 1) x + self / x^(k - 1)) / k`, rounding every division down.
+    ///
+    /// By the inequality of arithmetic and geometric means, one step from any
+    /// positive guess lands at or above the root, and from there every step
+    /// decreases until the floor of the root is reached. The first guess comes
+    /// from a floating-point logarithm, so only a few steps are needed.
+    ///
+    /// # Panics
+    /// Panics if `k` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("99"), BigUint::new("999,999").nth_root(3));
+    /// assert_eq!(BigUint::new("100"), BigUint::new("1,000,000").nth_root(3));
+    /// ```
+    pub fn nth_root(&self, k: u32) -> BigUint {
+        assert!(0 != k, "the zeroth root is undefined");
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+        if 1 == k {
+            return self.trimmed();
+        }
+        let step = |root: &BigUint| {
+            let mut next = div_rem(self, &root.pow(k - 1)).0;
+            let mut scaled = root.clone();
+            scaled *= k - 1;
+            next += &scaled;
+            next.div_rem_small(i64::from(k)).0
+        };
+        let mut root = step(&self.root_estimate(k));
+        loop {
+            let next = step(&root);
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+
+    /// Checks whether this number is the square of an integer.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert!(BigUint::new("152,415,787,532,388,367,501,905,199,875,019,052,100").is_perfect_square());
+    /// assert!(!BigUint::new("152,415,787,532,388,367,501,905,199,875,019,052,101").is_perfect_square());
+    /// ```
+    pub fn is_perfect_square(&self) -> bool {
+        // Only 22 of the 100 possible final buckets can end a square.
+        let last = self.buckets.first().map_or(0, |bucket| *bucket);
+        if !(0..BUCKET_CAP).any(|root| last == root * root % BUCKET_CAP) {
+            return false;
+        }
+        self.sqrt_rem().1.is_zero()
+    }
+
+    /// Checks whether this number is `a^k` for some integer `a` and some `k >= 2`.
+    /// Zero and one count as perfect powers.
+    ///
+    /// Only prime exponents need to be tried, since `a^(pq) = (a^q)^p`,
+    /// and none above the bit length of the number.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert!(BigUint::new("1,162,261,467").is_perfect_power()); // 3^19
+    /// assert!(!BigUint::new("1,162,261,468").is_perfect_power());
+    /// ```
+    pub fn is_perfect_power(&self) -> bool {
+        let bits = self.bits();
+        if bits <= 1 {
+            return true;
+        }
+        let number = self.trimmed();
+        (2..=bits as u32)
+            .filter(|k| prime::is_prime(u64::from(*k)))
+            .any(|k| number.nth_root(k).pow(k) == number)
+    }
+
+    /// Implements the Schonhage-Strassen algorithm of Multiplication.
+    /// Uses the Fast Fourier Transform to convole the two numbers and then
+    /// inverse Fast Fourier Transform them back. Applies necessary carrying,
+    /// and then returns the result of the multiplication.
+    ///
+    /// Unlike the `*` operator, a failure in the transform is returned
+    /// as an `NttError` rather than causing a panic.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let lhs = BigUint::new("375");
+    /// let rhs = BigUint::new("859");
+    /// assert_eq!(Ok(BigUint::new("322,125")), lhs.checked_mul(&rhs));
+    /// assert_eq!(Err(NttError::EmptyInput), lhs.checked_mul(&BigUint::empty()));
+    /// ```
+    pub fn checked_mul(&self, rhs: &BigUint) -> Result<BigUint, NttError> {
+        let mut convolution = fft_convolution(&self.buckets, &rhs.buckets)?;
+        apply_carries(&mut convolution);
+        // The convolution has every trailing zero stripped, including a zero product.
+        if convolution.is_empty() {
+            convolution.push(0);
+        }
+
+        Ok(BigUint::from_buckets(convolution))
+    }
+
+    /// Multiplies with a complex double-precision FFT instead of the NTT.
+    ///
+    /// Pairs of buckets are packed into limbs below `10^4`, which fit in 16 bits,
+    /// so half as many points are transformed as with `checked_mul`. Floating-point
+    /// rounding is only safe while `fft_f64_error_bound` stays below one half; past
+    /// that, the product falls back to the NTT, so the result is always exact.
+    ///
+    /// # Panics
+    /// Panics like the `*` operator, e.g. if either operand is empty.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let lhs = BigUint::new("123,456,789,987,654,321");
+    /// let rhs = BigUint::new("192,837,465,564,738,291");
+    /// assert_eq!(&lhs * &rhs, lhs.mul_fft_f64(&rhs));
+    /// ```
+    pub fn mul_fft_f64(&self, rhs: &BigUint) -> BigUint {
+        match fft::convolution(&to_limbs(&self.buckets), &to_limbs(&rhs.buckets)) {
+            Some(convolution) => {
+                // A limb at position k is the bucket at position 2k, carried upward.
+                let mut buckets = vec![0; 2 * convolution.len()];
+                for (k, coefficient) in convolution.into_iter().enumerate() {
+                    buckets[2 * k] = coefficient;
                 }
+                apply_carries(&mut buckets);
+                BigUint::from_buckets(buckets)
+            }
+            None => self * rhs,
+        }
+    }
 
-                if 1 == n & 1 {
-                    first
-                } else {
-                    second
+    /// Returns the proven bound on the rounding error of any coefficient when
+    /// `mul_fft_f64` multiplies these operands with a floating-point FFT.
+    /// Below `0.5`, rounding recovers every coefficient exactly.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let small = BigUint::new(&"9".repeat(1_000));
+    /// assert!(small.fft_f64_error_bound(&small) < 0.001);
+    /// let huge = BigUint::new(&"9".repeat(2_000_000));
+    /// assert!(huge.fft_f64_error_bound(&huge) > 0.5);
+    /// ```
+    pub fn fft_f64_error_bound(&self, rhs: &BigUint) -> f64 {
+        fft::error_bound(&to_limbs(&self.buckets), &to_limbs(&rhs.buckets))
+    }
+
+    /// Calculates `self^exponent % modulus`.
+    ///
+    /// The exponent is scanned with left-to-right sliding windows, so only one
+    /// multiplication is needed per window of up to six bits. Each product is reduced
+    /// with Montgomery reduction when the modulus is coprime to 10, the base of the
+    /// buckets. Otherwise, for even moduli and multiples of 5, it is reduced with
+    /// Barrett reduction. Neither needs a long division inside the loop.
+    ///
+    /// # Panics
+    /// Panics if the modulus is zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let base = BigUint::new("4");
+    /// assert_eq!(BigUint::new("445"), base.modpow(&BigUint::new("13"), &BigUint::new("497")));
+    /// assert_eq!(BigUint::new("64"), base.modpow(&BigUint::new("13"), &BigUint::new("100")));
+    /// ```
+    pub fn modpow(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        assert!(!modulus.is_zero(), "modulus must be non-zero");
+        let bits = exponent.to_bits();
+        let base = rem(self, modulus);
+        let one = rem(&BigUint::one(), modulus);
+        let mut modulus = modulus.buckets.clone();
+        trim(&mut modulus);
+
+        if 1 == modulus[0] % 2 && 0 != modulus[0] % 5 {
+            let reducer = MontgomeryReducer::new(modulus);
+            let base = reducer.to_montgomery(&base);
+            let one = reducer.to_montgomery(&one);
+            let result = sliding_window(base, one, &bits, |lhs, rhs| reducer.mul(lhs, rhs));
+            reducer.reduce(result.buckets)
+        } else {
+            let reducer = BarrettReducer::new(modulus);
+            sliding_window(base, one, &bits, |lhs, rhs| {
+                reducer.reduce(mul_slices(&lhs.buckets, &rhs.buckets))
+            })
+        }
+    }
+
+    /// Tests whether this number is probably prime.
+    ///
+    /// Numbers that fit in a `u64` are answered exactly by the deterministic
+    /// Miller-Rabin test behind `nordint::is_prime`. Larger numbers are first
+    /// trial-divided by small primes and then put through the Baillie-PSW test
+    /// (a base-2 Miller-Rabin round followed by a strong Lucas test), for which
+    /// no counterexample is known. Each of the additional `rounds` runs one more
+    /// Miller-Rabin round, with a witness drawn from `[2, n - 2]` by a generator
+    /// with a fixed seed, so the same number always gets the same answer.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// // 2^127 - 1
+    /// assert!(BigUint::new("170141183460469231731687303715884105727").is_probable_prime(5));
+    /// assert!(!BigUint::new("170141183460469231731687303715884105729").is_probable_prime(5));
+    /// ```
+    pub fn is_probable_prime(&self, rounds: usize) -> bool {
+        if let Some(number) = self.to_u64() {
+            return prime::is_prime(number);
+        }
+        if SMALL_PRIMES
+            .iter()
+            .any(|&prime| 0 == div_rem_small(&self.buckets, prime).1)
+        {
+            return false;
+        }
+
+        let one = BigUint::one();
+        let n_minus_one = self - &one;
+        let s = n_minus_one.trailing_zeros().unwrap_or(0) as usize;
+        let d = n_minus_one.shr_bits(s);
+        let is_strong_probable_prime = |witness: &BigUint| {
+            let mut x = witness.modpow(&d, self);
+            if x == one || x == n_minus_one {
+                return true;
+            }
+            for _ in 1..s {
+                x = mul_mod(&x, &x, self);
+                if x == n_minus_one {
+                    return true;
                 }
             }
+            false
+        };
+
+        // Drawing a few more buckets than the number has keeps the bias
+        // of reducing them into `[0, n - 4]` negligible.
+        let span = self - 3_u32;
+        let mut state = WITNESS_SEED;
+        let mut random_witness = || {
+            let buckets = (0..self.buckets.len() + 2)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    (state % BUCKET_CAP as u64) as i64
+                })
+                .collect();
+            &rem(&BigUint::from_buckets(buckets), &span) + 2_u32
+        };
+
+        is_strong_probable_prime(&BigUint::from(2_u32))
+            && self.is_strong_lucas_probable_prime()
+            && (0..rounds).all(|_| is_strong_probable_prime(&random_witness()))
+    }
+
+    /// Returns the smallest probable prime strictly greater than this number.
+    /// Each candidate is checked with `is_probable_prime(0)`, i.e. Baillie-PSW.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let googol = BigUint::new(&format!("1{}", "0".repeat(100)));
+    /// let expected = BigUint::new(&format!("1{}267", "0".repeat(97)));
+    /// assert_eq!(expected, googol.next_prime());
+    /// ```
+    pub fn next_prime(&self) -> BigUint {
+        if let Some(prime) = self.to_u64().and_then(prime::next_prime) {
+            return BigUint::from(prime);
+        }
+        // Step through the odd numbers only.
+        let two = BigUint::from(2_u32);
+        let mut candidate = self.clone();
+        candidate += &BigUint::one();
+        if candidate.is_even() {
+            candidate += &BigUint::one();
+        }
+        while !candidate.is_probable_prime(0) {
+            candidate += &two;
         }
+        candidate
     }
 
-    /// Calculates the factorial of a given number
-    pub fn fac(n: u32) -> BigUint {
-        let mut result = BigUint::one();
-        (1..n + 1).rev().for_each(|x| {
-            result *= x;
-        });
-        result
+    /// Returns the largest probable prime strictly less than this number,
+    /// or `None` if there is no such prime.
+    /// Each candidate is checked with `is_probable_prime(0)`, i.e. Baillie-PSW.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(None, BigUint::from(2_u32).prev_prime());
+    /// assert_eq!(Some(BigUint::from(97_u32)), BigUint::from(100_u32).prev_prime());
+    /// ```
+    pub fn prev_prime(&self) -> Option<BigUint> {
+        if let Some(number) = self.to_u64() {
+            return prime::prev_prime(number).map(BigUint::from);
+        }
+        // Step through the odd numbers only.
+        let two = BigUint::from(2_u32);
+        let mut candidate = self - &BigUint::one();
+        if candidate.is_even() {
+            candidate = &candidate - &BigUint::one();
+        }
+        while !candidate.is_probable_prime(0) {
+            candidate = &candidate - &two;
+        }
+        Some(candidate)
+    }
+
+    /// Strong Lucas probable-prime test with Selfridge's parameters:
+    /// `D` is the first of 5, -7, 9, -11, ... with Jacobi symbol `(D / n) == -1`,
+    /// `P = 1` and `Q = (1 - D) / 4`. Expects an odd number larger than a `u64`.
+    fn is_strong_lucas_probable_prime(&self) -> bool {
+        let mut d: i64 = 5;
+        loop {
+            match jacobi(d, self) {
+                -1 => break,
+                0 => return false,
+                _ => {}
+            }
+            // A perfect square never yields -1, so check for one
+            // before searching any further.
+            if 13 == d && self.is_perfect_square() {
+                return false;
+            }
+            d = if 0 < d { -(d + 2) } else { -d + 2 };
+        }
+        let q = (1 - d) / 4;
+
+        // Lift the small signed parameters into the ring of integers mod n.
+        let to_residue = |value: i64| {
+            let magnitude = BigUint::from(value.unsigned_abs());
+            if 0 <= value {
+                magnitude
+            } else {
+                self - &magnitude
+            }
+        };
+        let d_mod = to_residue(d);
+        let q_mod = to_residue(q);
+        let half_mod = |mut value: BigUint| {
+            if !value.is_even() {
+                value += self;
+            }
+            value.shr_bits(1)
+        };
+        let sub_mod = |lhs: &BigUint, rhs: &BigUint| {
+            if lhs >= rhs {
+                lhs - rhs
+            } else {
+                let mut difference = lhs.clone();
+                difference += self;
+                &difference - rhs
+            }
+        };
+
+        let mut n_plus_one = self.clone();
+        n_plus_one += &BigUint::one();
+        let s = n_plus_one.trailing_zeros().unwrap_or(0) as usize;
+        let exponent = n_plus_one.shr_bits(s);
+
+        // Walk the bits of the exponent from the most significant end,
+        // doubling the index each step and incrementing it on set bits.
+        let mut u = BigUint::zero();
+        let mut v = BigUint::from(2_u32);
+        let mut q_k = BigUint::one();
+        for bit in exponent.to_bits().into_iter().rev() {
+            u = mul_mod(&u, &v, self);
+            let mut two_q_k = q_k.clone();
+            two_q_k += &q_k;
+            v = sub_mod(&mul_mod(&v, &v, self), &rem(&two_q_k, self));
+            q_k = mul_mod(&q_k, &q_k, self);
+            if bit {
+                let mut next_u = u.clone();
+                next_u += &v;
+                let mut next_v = mul_mod(&d_mod, &u, self);
+                next_v += &v;
+                u = half_mod(rem(&next_u, self));
+                v = half_mod(rem(&next_v, self));
+                q_k = mul_mod(&q_k, &q_mod, self);
+            }
+        }
+
+        if u.is_zero() || v.is_zero() {
+            return true;
+        }
+        for _ in 1..s {
+            let mut two_q_k = q_k.clone();
+            two_q_k += &q_k;
+            v = sub_mod(&mul_mod(&v, &v, self), &rem(&two_q_k, self));
+            if v.is_zero() {
+                return true;
+            }
+            q_k = mul_mod(&q_k, &q_k, self);
+        }
+        false
+    }
+
+    /// Returns true if the number is zero (or empty).
+    pub(crate) fn is_zero(&self) -> bool {
+        self.buckets.iter().all(|bucket| 0 == *bucket)
+    }
+
+    /// Returns true if the lowest-order digit is even.
+    pub(crate) fn is_even(&self) -> bool {
+        self.buckets.first().is_none_or(|bucket| 0 == bucket & 1)
+    }
+
+    /// Converts to a `u64`, if the value fits.
+    pub(crate) fn to_u64(&self) -> Option<u64> {
+        self.buckets.iter().rev().try_fold(0_u64, |number, bucket| {
+            number
+                .checked_mul(BUCKET_CAP as u64)?
+                .checked_add(*bucket as u64)
+        })
+    }
+
+    /// Estimates the base-10 logarithm from the highest-order buckets.
+    /// Returns negative infinity for zero.
+    fn log10(&self) -> f64 {
+        let len = self.bucket_len();
+        let leading = self.buckets[..len]
+            .iter()
+            .rev()
+            .take(8)
+            .enumerate()
+            .map(|(i, bucket)| *bucket as f64 * (BUCKET_CAP as f64).powi(-(i as i32)))
+            .sum::<f64>();
+        leading.log10() + (DIGITS_PER_BUCKET * len.saturating_sub(1)) as f64
+    }
+
+    /// Approximates the `k`th root from `log10`, to about fifteen significant digits.
+    fn root_estimate(&self, k: u32) -> BigUint {
+        let exponent = self.log10() / f64::from(k);
+        // Keep the mantissa within the range where an f64 holds every integer exactly.
+        let shift = (exponent.floor() as usize).saturating_sub(14);
+        let mantissa = BigUint::from(10_f64.powf(exponent - shift as f64).ceil() as u64);
+        if 0 == shift {
+            mantissa
+        } else {
+            &mantissa * &BigUint::pow_of_ten(shift)
+        }
+    }
+
+    /// Returns a copy without any high-order zero buckets, where an empty number becomes zero.
+    pub(crate) fn trimmed(&self) -> BigUint {
+        if self.is_zero() {
+            BigUint::zero()
+        } else {
+            BigUint::from_buckets(self.buckets.clone())
+        }
+    }
+
+    /// Returns true if the number has no buckets at all.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Returns the raw buckets, least significant first.
+    pub(crate) fn buckets(&self) -> &[i64] {
+        &self.buckets
+    }
+
+    /// Returns the number of buckets, ignoring any high-order zero buckets.
+    pub(crate) fn bucket_len(&self) -> usize {
+        self.buckets.iter().rposition(|bucket| 0 != *bucket).map_or(0, |top| top + 1)
+    }
+
+    /// Reads the buckets from `start` up to the top as a single integer.
+    /// The caller keeps the range short enough to fit in an i64.
+    pub(crate) fn buckets_from(&self, start: usize) -> i64 {
+        self.buckets
+            .iter()
+            .skip(start)
+            .rev()
+            .fold(0, |value, bucket| value * BUCKET_CAP + bucket)
+    }
+
+    /// Divides by a small divisor, returning the quotient and remainder.
+    pub(crate) fn div_rem_small(&self, divisor: i64) -> (BigUint, i64) {
+        let (quotient, remainder) = div_rem_small(&self.buckets, divisor);
+        (BigUint::from_buckets(quotient), remainder)
+    }
+
+    /// Wraps raw buckets, removing any high-order zero buckets.
+    pub(crate) fn from_buckets(mut buckets: Vec<i64>) -> BigUint {
+        trim(&mut buckets);
+        BigUint { buckets }
+    }
+}
+
+impl Ord for BigUint {
+    /// Compares numerically, from the highest-order bucket down.
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        cmp_slices(&self.buckets, &other.buckets)
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
+impl PartialEq for BigUint {
+    /// Compares numerically, so that high-order zero buckets
+    /// do not make two equal numbers differ, in agreement with `Ord`.
+    fn eq(&self, other: &BigUint) -> bool {
+        Ordering::Equal == cmp_slices(&self.buckets, &other.buckets)
+    }
+}
+
+impl Eq for BigUint {}
+
 impl Default for BigUint {
     /// Default `BigUint` is empty.
     fn default() -> BigUint {
@@ -161,6 +899,20 @@ impl Default for BigUint {
     }
 }
 
+/// Converts a u8 to a BigUint
+impl From<u8> for BigUint {
+    fn from(number: u8) -> BigUint {
+        BigUint::from(u32::from(number))
+    }
+}
+
+/// Converts a u16 to a BigUint
+impl From<u16> for BigUint {
+    fn from(number: u16) -> BigUint {
+        BigUint::from(u32::from(number))
+    }
+}
+
 /// Converts a u32 to a BigUint
 impl From<u32> for BigUint {
     fn from(mut number: u32) -> BigUint {
@@ -233,7 +985,7 @@ impl FromStr for BigUint {
         }
 
         for digit in num_as_str.chars() {
-            if !digit.is_digit(10) {
+            if !digit.is_ascii_digit() {
                 return Err(Self::Err::invalid());
             }
         }
@@ -256,11 +1008,11 @@ impl FromStr for BigUint {
     }
 }
 
-impl ToString for BigUint {
-    /// Converts a BigUint into a string of an integer represented in base 10.
-    fn to_string(&self) -> String {
+impl fmt::Display for BigUint {
+    /// Formats a BigUint as an integer represented in base 10.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.buckets.is_empty() {
-            return String::new();
+            return Ok(());
         }
         // Avoid generating leading zeros on the highest-order bucket.
         let mut num_as_string = self.buckets[self.buckets.len() - 1].to_string();
@@ -272,11 +1024,11 @@ impl ToString for BigUint {
             }
             num_as_string += number;
         }
-        num_as_string
+        f.pad_integral(true, "", &num_as_string)
     }
 }
 
-impl<'a> AddAssign<&'a BigUint> for BigUint {
+impl AddAssign<&BigUint> for BigUint {
     /// Adds a BigUint into another BigUint
     fn add_assign(&mut self, rhs: &BigUint) {
         let lhs = &mut self.buckets;
@@ -304,6 +1056,40 @@ impl<'a> AddAssign<&'a BigUint> for BigUint {
     }
 }
 
+impl Sum for BigUint {
+    /// Adds up the numbers, starting from zero. Empty numbers are skipped.
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::zero(), |mut sum, number| {
+            sum += &number;
+            sum
+        })
+    }
+}
+
+impl<'a> Sum<&'a BigUint> for BigUint {
+    /// Adds up the numbers, starting from zero. Empty numbers are skipped.
+    fn sum<I: Iterator<Item = &'a BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::zero(), |mut sum, number| {
+            sum += number;
+            sum
+        })
+    }
+}
+
+impl Product for BigUint {
+    /// Multiplies the numbers with `product_tree`. Empty numbers count as zero.
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        product_tree(&iter.collect::<Vec<_>>())
+    }
+}
+
+impl<'a> Product<&'a BigUint> for BigUint {
+    /// Multiplies the numbers with `product_tree`. Empty numbers count as zero.
+    fn product<I: Iterator<Item = &'a BigUint>>(iter: I) -> BigUint {
+        product_tree(&iter.cloned().collect::<Vec<_>>())
+    }
+}
+
 /// Adds two slices point-wise, carrying at the limit for each bucket
 #[inline]
 fn add_slices(lhs: &mut [i64], rhs: &[i64]) -> i64 {
@@ -320,342 +1106,441 @@ fn add_slices(lhs: &mut [i64], rhs: &[i64]) -> i64 {
     carry
 }
 
-/// Multiplies an i64 into each bucket of the BigUint
-impl MulAssign<u32> for BigUint {
-    fn mul_assign(&mut self, rhs: u32) {
-        let rhs = rhs as i64;
-        if rhs == 1 || self.buckets.is_empty() { 
-           return;
+/// Subtracts two slices point-wise, borrowing at the limit for each bucket.
+/// Returns the outstanding borrow, which is only non-zero if `rhs > lhs`.
+#[inline]
+fn sub_slices(lhs: &mut [i64], rhs: &[i64]) -> i64 {
+    let mut borrow = 0;
+    for (index, lx) in lhs.iter_mut().enumerate() {
+        let rx = rhs.get(index).cloned().unwrap_or(0);
+        if index >= rhs.len() && 0 == borrow {
+            break;
         }
-        if rhs == 0 {
-            self.buckets = vec![0];
-            return;
+        *lx -= rx + borrow;
+        borrow = if *lx < 0 {
+            *lx += BUCKET_CAP;
+            1
+        } else {
+            0
         }
+    }
+    borrow
+}
+
+/// Multiplies two slices with the O(n^2) schoolbook method.
+/// It is used wherever an operand stays small enough that the transform setup
+/// would dominate: products with at most `SCHOOLBOOK_BUCKETS` buckets on one side,
+/// the trial products of long division, the square in `sqrt_rem`, and the modular
+/// products behind primality testing, `modpow` and the Montgomery and Barrett reducers.
+fn mul_slices(lhs: &[i64], rhs: &[i64]) -> Vec<i64> {
+    let mut product = vec![0; lhs.len() + rhs.len()];
+    for (i, lx) in lhs.iter().enumerate() {
         let mut carry = 0;
-        for bucket in &mut self.buckets {
-            *bucket *= rhs;
-            *bucket += carry;
-            carry = *bucket / BUCKET_CAP;
-            *bucket %= BUCKET_CAP;
-        }
-        if 0 < carry {
-            self.buckets.extend_from_slice(&BigUint::from(carry as u64).buckets[..]);
+        for (j, rx) in rhs.iter().enumerate() {
+            let bucket = product[i + j] + lx * rx + carry;
+            carry = bucket / BUCKET_CAP;
+            product[i + j] = bucket % BUCKET_CAP;
         }
+        product[i + rhs.len()] += carry;
+    }
+    product
+}
+
+/// Divides a slice by a small divisor, returning the quotient and remainder.
+fn div_rem_small(buckets: &[i64], divisor: i64) -> (Vec<i64>, i64) {
+    let mut quotient = vec![0; buckets.len()];
+    let mut remainder = 0;
+    for (index, bucket) in buckets.iter().enumerate().rev() {
+        let current = remainder * BUCKET_CAP + bucket;
+        quotient[index] = current / divisor;
+        remainder = current % divisor;
     }
+    trim(&mut quotient);
+    (quotient, remainder)
 }
 
-/// Number-Theoretic Transform Error
-type NttError<T> = Result<T, String>;
+/// Long division of two BigUints, returning the quotient and remainder.
+/// Each quotient bucket is estimated from the leading buckets in floating point
+/// and then corrected, since the estimate can be off by one.
+pub(crate) fn div_rem(lhs: &BigUint, rhs: &BigUint) -> (BigUint, BigUint) {
+    assert!(!rhs.is_zero(), "attempt to divide a BigUint by zero");
+    let mut divisor = rhs.buckets.clone();
+    trim(&mut divisor);
+    let top = divisor.len() - 1;
+    let leading = |buckets: &[i64]| {
+        (top.saturating_sub(6)..buckets.len())
+            .rev()
+            .fold(0.0, |value, index| {
+                value + buckets[index] as f64 * (BUCKET_CAP as f64).powi(index as i32 - top as i32)
+            })
+    };
+    let divisor_leading = leading(&divisor);
+    let mut quotient = vec![0; lhs.buckets.len()];
+    let mut remainder: Vec<i64> = Vec::with_capacity(divisor.len() + 1);
 
-/// This trait represents x^y % m
-trait PowMod<E, M>
-where
-    Self: Copy + Mul<E> + Rem<M>,
-{
-    type Return;
-    fn pow_mod(self, exponent: E, modulus: M) -> Self::Return;
+    for (index, bucket) in lhs.buckets.iter().enumerate().rev() {
+        remainder.insert(0, *bucket);
+        trim(&mut remainder);
+        if Ordering::Less == cmp_slices(&remainder, &divisor) {
+            continue;
+        }
+        let mut digit = min((leading(&remainder) / divisor_leading) as i64, BUCKET_CAP - 1);
+        let mut product = mul_slices(&divisor, &[digit]);
+        while Ordering::Greater == cmp_slices(&product, &remainder) {
+            digit -= 1;
+            sub_slices(&mut product, &divisor);
+        }
+        sub_slices(&mut remainder, &product);
+        trim(&mut remainder);
+        while Ordering::Less != cmp_slices(&remainder, &divisor) {
+            digit += 1;
+            sub_slices(&mut remainder, &divisor);
+            trim(&mut remainder);
+        }
+        quotient[index] = digit;
+    }
+    (BigUint::from_buckets(quotient), BigUint::from_buckets(remainder))
+}
+
+/// Calculates `lhs % rhs`
+pub(crate) fn rem(lhs: &BigUint, rhs: &BigUint) -> BigUint {
+    div_rem(lhs, rhs).1
 }
 
-/// This trait represents the modular inverse of x^y % m
-trait InversePowMod<E, M>
-where
-    Self: Copy + Mul<E> + Rem<M>,
-{
-    type Return;
-    fn inv_pow_mod(self, exponent: E, modulus: M) -> Self::Return;
+/// Calculates `lhs * rhs % modulus`
+pub(crate) fn mul_mod(lhs: &BigUint, rhs: &BigUint, modulus: &BigUint) -> BigUint {
+    rem(&BigUint::from_buckets(mul_slices(&lhs.buckets, &rhs.buckets)), modulus)
 }
 
-/// Defining x^y % m for the i64 type.
-/// This code fails when modulus is larger than sqrt(std::i64::MAX):
-///    Allowing self to get that large will wrap the integer when squaring.
-///    This will either panic!() in debug mode, or wrap and produce and incorrect
-///    result in production mode. I have not yet found an algorithm that will
-///    account for the wrapping. For now, I am limited to modlulus < sqrt(std::i64::MAX)
-impl PowMod<i64, i64> for i64 {
-    type Return = Self;
-    fn pow_mod(mut self, mut exponent: i64, modulus: i64) -> Self {
-        let mut result = 1;
-        self %= modulus;
-        while 0 < exponent {
-            if 1 == exponent & 1 {
-                result = result * self % modulus;
+/// Multiplies all of the factors together as a balanced binary tree, so that the
+/// two operands of every multiplication are about the same size and the large ones
+/// go through the fast multiplier. The product of no factors is one.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// let factors: Vec<BigUint> = (1..=20_u32).map(BigUint::from).collect();
+/// assert_eq!(BigUint::fac(20), product_tree(&factors));
+/// assert_eq!(BigUint::one(), product_tree(&[]));
+/// ```
+pub fn product_tree(factors: &[BigUint]) -> BigUint {
+    match factors.len() {
+        0 => BigUint::one(),
+        1 => factors[0].trimmed(),
+        len => {
+            let lhs = product_tree(&factors[..len / 2]);
+            let rhs = product_tree(&factors[len / 2..]);
+            if min(lhs.buckets.len(), rhs.buckets.len()) <= SCHOOLBOOK_BUCKETS {
+                BigUint::from_buckets(mul_slices(&lhs.buckets, &rhs.buckets))
+            } else {
+                &lhs * &rhs
             }
-            self = self.pow(2) % modulus;
-            exponent >>= 1;
         }
-        result
     }
 }
 
-/// Defining modular inverse of x^y % m for the i64 type.
-/// This is a naive algorithm sufficient for the purpose of this exercise.
-impl InversePowMod<i64, i64> for i64 {
-    type Return = Self;
-    fn inv_pow_mod(self, exponent: i64, modulus: i64) -> Self {
-        let pow_mod = self.pow_mod(exponent, modulus);
-        for i in 0..modulus {
-            if 1 == pow_mod * i % modulus {
-                return i;
-            }
+/// A 2x2 matrix, stored row by row.
+type Matrix = [[BigUint; 2]; 2];
+
+/// Multiplies two 2x2 matrices, passing every entry through `reduce`.
+fn mul_matrices(lhs: &Matrix, rhs: &Matrix, reduce: &dyn Fn(BigUint) -> BigUint) -> Matrix {
+    let entry = |row: usize, column: usize| {
+        let mut sum = &lhs[row][0] * &rhs[0][column];
+        sum += &(&lhs[row][1] * &rhs[1][column]);
+        reduce(sum)
+    };
+    [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]]
+}
+
+/// Raises a 2x2 matrix to the power whose bits are given, least significant first.
+fn matrix_pow(matrix: Matrix, bits: &[bool], reduce: &dyn Fn(BigUint) -> BigUint) -> Matrix {
+    let identity = [
+        [reduce(BigUint::one()), BigUint::zero()],
+        [BigUint::zero(), reduce(BigUint::one())],
+    ];
+    sliding_window(matrix, identity, bits, |lhs, rhs| mul_matrices(lhs, rhs, reduce))
+}
+
+/// Calculates `x * lhs + y * rhs` for small cofactors, one of which may be negative,
+/// when the result is known not to be. Each cofactor must stay below `10^16` in magnitude.
+pub(crate) fn linear_combination(lhs: &BigUint, x: i64, rhs: &BigUint, y: i64) -> BigUint {
+    let len = max(lhs.buckets.len(), rhs.buckets.len());
+    let mut buckets = Vec::with_capacity(len + 9);
+    let mut carry = 0;
+    for index in 0..len {
+        let value = x * lhs.buckets.get(index).unwrap_or(&0) + y * rhs.buckets.get(index).unwrap_or(&0) + carry;
+        buckets.push(value.rem_euclid(BUCKET_CAP));
+        carry = value.div_euclid(BUCKET_CAP);
+    }
+    debug_assert!(0 <= carry, "a linear combination came out negative");
+    while 0 < carry {
+        buckets.push(carry % BUCKET_CAP);
+        carry /= BUCKET_CAP;
+    }
+    BigUint::from_buckets(buckets)
+}
+
+/// Calculates the Jacobi symbol `(a / n)` for a small signed `a` and a large odd `n`,
+/// using quadratic reciprocity to reduce to a symbol over machine words.
+fn jacobi(a: i64, n: &BigUint) -> i32 {
+    let n_mod_8 = div_rem_small(&n.buckets, 8).1;
+    let mut result = 1;
+    // (-1 / n) is -1 exactly when n = 3 mod 4.
+    if 0 > a && 3 == n_mod_8 % 4 {
+        result = -result;
+    }
+    let mut a = a.unsigned_abs();
+    if 0 == a {
+        return 0;
+    }
+    // (2 / n) is -1 exactly when n = 3 or 5 mod 8.
+    while 0 == a & 1 {
+        a >>= 1;
+        if 3 == n_mod_8 || 5 == n_mod_8 {
+            result = -result;
         }
-        unreachable!();
     }
+    // (a / n) = (n / a), unless both are 3 mod 4.
+    if 3 == a % 4 && 3 == n_mod_8 % 4 {
+        result = -result;
+    }
+    let n_mod_a = div_rem_small(&n.buckets, a as i64).1 as u64;
+    result * prime::jacobi(n_mod_a, a)
 }
 
-/// Naive primality test:
-///   Handle cases: 0, 1, 2, even numbers
-///   Step through the odd numbers up to sqrt(number) looking for divisors.
-fn is_prime(number: i64) -> bool {
-    if 2 == number {
-        return true;
-    } // 2
-    if 1 >= number {
-        return false;
-    } // 0 or 1
-    if 0 == number & 1 {
-        return false;
-    } // even, not 2
+/// Montgomery reduction with `R = BUCKET_CAP^k`, where the modulus has `k` buckets.
+/// `R` must be coprime to the modulus, so the modulus must be coprime to 10.
+struct MontgomeryReducer {
+    modulus: Vec<i64>,
+    /// `-modulus^(-1) mod BUCKET_CAP`
+    inverse: i64,
+}
 
-    let sqrt = (number as f64).sqrt() as i64 + 1;
-    for divisor in (3..=sqrt).step_by(2) {
-        if 0 == number % divisor {
-            return false;
+impl MontgomeryReducer {
+    fn new(modulus: Vec<i64>) -> MontgomeryReducer {
+        let inverse = (1..BUCKET_CAP)
+            .find(|candidate| 1 == modulus[0] * candidate % BUCKET_CAP)
+            .expect("the modulus is coprime to the bucket capacity");
+        MontgomeryReducer {
+            modulus,
+            inverse: BUCKET_CAP - inverse,
         }
     }
-    true
-}
 
-/// Returns the set of prime factors of a given number.
-fn prime_factors_of(mut number: i64) -> Vec<i64> {
-    if is_prime(number) {
-        return vec![number];
+    /// Calculates `value * R mod N` for `value < N`.
+    fn to_montgomery(&self, value: &BigUint) -> BigUint {
+        let mut shifted = vec![0; self.modulus.len()];
+        shifted.extend_from_slice(&value.buckets);
+        let modulus = BigUint::from_buckets(self.modulus.clone());
+        rem(&BigUint::from_buckets(shifted), &modulus)
     }
 
-    let mut factors = Vec::new();
-    let half = number / 2 + 1;
-    let mut pushed = false;
+    /// Multiplies two numbers in Montgomery form.
+    fn mul(&self, lhs: &BigUint, rhs: &BigUint) -> BigUint {
+        self.reduce(mul_slices(&lhs.buckets, &rhs.buckets))
+    }
 
-    for factor in 2..half {
-        if number == 1 {
-            break;
-        }
-        while 0 == number % factor {
-            number /= factor;
-            if !pushed {
-                factors.push(factor);
-                pushed = true;
+    /// Calculates `value * R^(-1) mod N` for `value < N * R`.
+    /// One bucket at a time, a multiple of the modulus is added that clears
+    /// the lowest bucket, and then all `k` cleared buckets are shifted away.
+    fn reduce(&self, mut value: Vec<i64>) -> BigUint {
+        let k = self.modulus.len();
+        let len = max(value.len(), 2 * k) + 1;
+        value.resize(len, 0);
+        for i in 0..k {
+            let multiple = value[i] * self.inverse % BUCKET_CAP;
+            let mut carry = 0;
+            for (j, bucket) in self.modulus.iter().enumerate() {
+                let current = value[i + j] + multiple * bucket + carry;
+                value[i + j] = current % BUCKET_CAP;
+                carry = current / BUCKET_CAP;
+            }
+            let mut index = i + k;
+            while 0 != carry {
+                let current = value[index] + carry;
+                value[index] = current % BUCKET_CAP;
+                carry = current / BUCKET_CAP;
+                index += 1;
             }
         }
-        pushed = false;
+        let mut reduced = value.split_off(k);
+        if Ordering::Less != cmp_slices(&reduced, &self.modulus) {
+            sub_slices(&mut reduced, &self.modulus);
+        }
+        BigUint::from_buckets(reduced)
     }
+}
 
-    factors
+/// Barrett reduction, where `mu = floor(BUCKET_CAP^(2k) / N)` is computed once
+/// so that reducing a product takes two multiplications instead of a long division.
+struct BarrettReducer {
+    modulus: Vec<i64>,
+    mu: Vec<i64>,
 }
 
-/// Finds a modulus M such that:
-///   M is a prime number.
-///   M is larger than the number of elements
-///   M is is larger than the value of any element
-fn find_convolution_modulus(n: i64, lhs: &[i64], rhs: &[i64]) -> NttError<i64> {
-    let max_elem = 
-    max(
-        *lhs.iter()
-            .max()
-            .expect("[NttError]: Could not define a maximum element."),
-        *rhs.iter()
-            .max()
-            .expect("[NttError]: Could not define a maximum element.")
-    );
-    let minimum_modulus = max_elem.pow(2) * n + 1;
-    let start = (minimum_modulus - 1) / n;
+impl BarrettReducer {
+    fn new(modulus: Vec<i64>) -> BarrettReducer {
+        let mut power = vec![0; 2 * modulus.len()];
+        power.push(1);
+        let mu = div_rem(&BigUint::from_buckets(power), &BigUint::from_buckets(modulus.clone())).0;
+        BarrettReducer {
+            modulus,
+            mu: mu.buckets,
+        }
+    }
 
-    for k in start.. {
-        let modulus = k * n + 1;
-        if modulus > minimum_modulus && is_prime(modulus) {
-            return Ok(modulus);
+    /// Calculates `value mod N` for `value < N^2`. The quotient estimate
+    /// `(value / B^(k - 1)) * mu / B^(k + 1)` is at most two short,
+    /// so at most two subtractions of the modulus remain.
+    fn reduce(&self, mut value: Vec<i64>) -> BigUint {
+        let k = self.modulus.len();
+        if value.len() >= k {
+            let estimate = mul_slices(&value[k - 1..], &self.mu);
+            if estimate.len() > k + 1 {
+                let product = mul_slices(&estimate[k + 1..], &self.modulus);
+                if product.len() > value.len() {
+                    value.resize(product.len(), 0);
+                }
+                sub_slices(&mut value, &product);
+            }
         }
+        trim(&mut value);
+        while Ordering::Less != cmp_slices(&value, &self.modulus) {
+            sub_slices(&mut value, &self.modulus);
+            trim(&mut value);
+        }
+        BigUint::from_buckets(value)
     }
-    Err("[NttError]: Could not find working modulus for the provided vector.".to_string())
+}
 
+/// Packs each pair of buckets into a single limb below `BUCKET_CAP^2`.
+fn to_limbs(buckets: &[i64]) -> Vec<i64> {
+    buckets
+        .chunks(2)
+        .map(|pair| pair[0] + BUCKET_CAP * pair.get(1).cloned().unwrap_or(0))
+        .collect()
 }
 
-/// Finds a generator under the given modulus:
-/// Some number g is a generator for a modulus M if for each
-/// prime factor of (M - 1), g^((M - 1) / factor) mod M != 1
-/// I still do not fully understand why this works.
-fn find_generator(modulus: i64) -> NttError<i64> {
-    let max_value = modulus - 1;
-    let prime_factors = prime_factors_of(max_value);
-    for generator in 1..modulus {
-        if prime_factors
-            .iter()
-            .map(|factor| 1 != generator.pow_mod(max_value / factor, modulus))
-            .all(|not_one| not_one)
-        {
-            return Ok(generator);
-        }
+/// Removes high-order zero buckets, leaving at least one bucket.
+fn trim(buckets: &mut Vec<i64>) {
+    while 1 < buckets.len() && buckets.ends_with(&[0]) {
+        buckets.pop();
     }
-    Err(format!(
-        "[NttError]: No generator exists under the modulus `{}`",
-        modulus
-    ))
 }
 
-/// Finds the value of omega for the Number-Theore9803tic Transform under a given modulus M.
-/// Omega is defined as the following:
-///   `Let g = a generator under the modulus M`
-///   `Let k = (M - 1) / (the number of elements that will be transformed)`
-///   `Let omega = g^k mod M`
-fn find_omega(n: i64, modulus: i64) -> NttError<i64> {
-    let k = (modulus - 1) / n;
-    let generator = find_generator(modulus)?;
-    Ok(generator.pow_mod(k, modulus))
+/// Compares two slices numerically, ignoring any high-order zero buckets.
+fn cmp_slices(lhs: &[i64], rhs: &[i64]) -> Ordering {
+    let significant = |buckets: &[i64]| {
+        buckets
+            .iter()
+            .rposition(|bucket| 0 != *bucket)
+            .map_or(0, |position| position + 1)
+    };
+    let (lhs_len, rhs_len) = (significant(lhs), significant(rhs));
+    lhs_len
+        .cmp(&rhs_len)
+        .then_with(|| lhs[..lhs_len].iter().rev().cmp(rhs[..rhs_len].iter().rev()))
 }
 
-
-/// Collects every other element of a slice of [i64] given a starting index
-///     `Example: [1, 5, 3, 5, 2, 6, 9] where start_index == 0`
-///     `Returns: [1, 3, 2, 9]`
-/// 
-///     `Example: [1, 5, 3, 5, 2, 6, 9, 4] where start_index == 1`
-///     `Returns: [5, 5, 6, 4]`
-fn every_other_element_starting_at(start_index: usize, elements: &[i64]) -> Vec<i64> {
-    (start_index..elements.len())
-        .step_by(2)
-        .map(|i| elements[i])
-        .collect()
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+    /// Subtracts one BigUint from another.
+    /// Panics if the result would be negative.
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        assert!(
+            Ordering::Less != self.cmp(rhs),
+            "attempt to subtract a larger BigUint from a smaller one"
+        );
+        let mut buckets = self.buckets.clone();
+        sub_slices(&mut buckets, &rhs.buckets);
+        BigUint::from_buckets(buckets)
+    }
 }
 
-/// Recursively performs the Cooley-Tukey O(n log(n)) algorithm on a slice of [i64].
-/// The algorithm is performed using a number-theoretic transform, 
-/// where omega is the first of nth roots of unity under the provided modulus.
-/// n must be a power of two.
-/// 
-/// This function takes a modular exponentiation function defined on i64. 
-/// The alrogirthm is the same whether doing regular modular exponentiation 
-/// (used in the FFT) or inverse modular exponentiation (used in the IFFT).
-/// 
-/// https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm
-fn cooley_tukey<'a, F>(
-    n: i64,
-    omega: i64,
-    modulus: i64,
-    elements: &'a mut [i64],
-    mod_exp_fn: &F,
-) -> &'a mut [i64]
-where
-    F: Fn(i64, i64, i64) -> i64,
-{
-    let len = elements.len();
-    if len == 1 {
-        return elements;
-    }
-
-    let mut even_index_elements = every_other_element_starting_at(0, &elements);
-    let mut odd_index_elements  = every_other_element_starting_at(1, &elements);
-    let even_transformed = cooley_tukey(n, omega, modulus, &mut even_index_elements, mod_exp_fn);
-    let odd_transformed  = cooley_tukey(n, omega, modulus, &mut odd_index_elements,  mod_exp_fn);
-    let multiplier = n / len as i64;
-
-    for i in 0..len / 2 {
-        let lhs = even_transformed[i];
-        let rhs = mod_exp_fn(omega, multiplier * i as i64, modulus) * odd_transformed[i] % modulus;
-        elements[i] = (lhs + rhs) % modulus;
-        elements[i + len / 2] = ((lhs - rhs) + modulus) % modulus;
-    }
-
-    elements
-}
-
-/// The Fast Fourier Transform is implemented as a Number-Theoretic version
-/// of the Cooley-Tukey algorithm. The cooley_tukey function is called
-/// using modular exponentiation. 
-/// 
-/// The vectors that are passed in is adjusted to be a power of two
-/// that is equal to or larger than the sum of their lengths.
-/// 
-/// This function returns the omega and modulus used as part of the tuple.
-/// It is necessary that the IFFT algorithm uses the same omega and modulus.
-/// 
-/// The last item in the tuple is the pointwise-multiplied convolution of the two
-/// transformed vectors.
-fn fft_convolution(lhs: &mut Vec<i64>, rhs: &mut Vec<i64>) -> NttError<(i64, i64, Vec<i64>)> {
-    let n = ((lhs.len() + rhs.len()) as f64).log2().ceil().exp2() as i64;
-    let modulus = find_convolution_modulus(n, &lhs, &rhs)?;
-    let omega = find_omega(n, modulus)?;
-    let left_difference =  n - lhs.len() as i64;
-    let right_difference = n - rhs.len() as i64;
-
-    for _ in 0..left_difference {
-        lhs.push(0);
-    }
-    for _ in 0..right_difference {
-        rhs.push(0);
-    }
-
-    cooley_tukey(n, omega, modulus, lhs, &<i64>::pow_mod);
-    cooley_tukey(n, omega, modulus, rhs, &<i64>::pow_mod);
-
-
-    Ok((omega, modulus,
-        lhs
-        .iter()
-        .zip(rhs)
-        .map(|(lx, rx)| *lx * *rx % modulus)
-        .collect::<Vec<i64>>()
-    ))
-}
-
-/// The Fast Fourier Transform is implemented as a Number-Theoretic version
-/// of the Cooley-Tukey algorithm. The cooley_tukey function is called
-/// using inverse modular exponentiation. 
-/// 
-/// The IFFT must use the same omega and modulus that was used in the FFT.
-/// Additionally, each element is scaled by a power of the modular inverse of n.
-/// This is necessary to reverse the forward transform. 
-/// 
-/// Afterward, any extraneous elements that were added to make the original vector's 
-/// length a power of two are removed. The vector will be the same length as it was
-/// before being transformed.
-fn ifft(omega: i64, modulus: i64, elements: &mut Vec<i64>) -> NttError<()> {
-    let n = elements.len() as i64;
-
-    cooley_tukey(n, omega, modulus, elements, &<i64>::inv_pow_mod)
-        .iter_mut()
-        .for_each(|x| {
-            *x *= n.inv_pow_mod(1, modulus);
-            *x %= modulus;
-        });
+impl Sub<BigUint> for &BigUint {
+    type Output = BigUint;
+    /// Subtracts an owned BigUint from a borrowed one, writing the difference
+    /// into the buckets of the owned operand.
+    /// Panics if the result would be negative.
+    fn sub(self, mut rhs: BigUint) -> BigUint {
+        assert!(
+            Ordering::Less != self.cmp(&rhs),
+            "attempt to subtract a larger BigUint from a smaller one"
+        );
+        let len = max(self.buckets.len(), rhs.buckets.len());
+        rhs.buckets.resize(len, 0);
+        let mut borrow = 0;
+        for (index, bucket) in rhs.buckets.iter_mut().enumerate() {
+            *bucket = self.buckets.get(index).cloned().unwrap_or(0) - *bucket - borrow;
+            borrow = if *bucket < 0 {
+                *bucket += BUCKET_CAP;
+                1
+            } else {
+                0
+            }
+        }
+        trim(&mut rhs.buckets);
+        rhs
+    }
+}
 
-    while elements.ends_with(&[0]) {
-        elements.pop();
+impl SubAssign<&BigUint> for BigUint {
+    /// Subtracts one BigUint from another in place.
+    /// Panics if the result would be negative.
+    fn sub_assign(&mut self, rhs: &BigUint) {
+        assert!(
+            Ordering::Less != (*self).cmp(rhs),
+            "attempt to subtract a larger BigUint from a smaller one"
+        );
+        sub_slices(&mut self.buckets, &rhs.buckets);
+        trim(&mut self.buckets);
     }
+}
 
-    Ok(())
+/// Multiplies an i64 into each bucket of the BigUint
+impl MulAssign<u32> for BigUint {
+    fn mul_assign(&mut self, rhs: u32) {
+        let rhs = rhs as i64;
+        if rhs == 1 || self.buckets.is_empty() { 
+           return;
+        }
+        if rhs == 0 {
+            self.buckets = vec![0];
+            return;
+        }
+        let mut carry = 0;
+        for bucket in &mut self.buckets {
+            *bucket *= rhs;
+            *bucket += carry;
+            carry = *bucket / BUCKET_CAP;
+            *bucket %= BUCKET_CAP;
+        }
+        if 0 < carry {
+            self.buckets.extend_from_slice(&BigUint::from(carry as u64).buckets[..]);
+        }
+    }
 }
 
-impl<'a, 'b> Mul<&'b BigUint> for &'a BigUint {
+impl Mul<&BigUint> for &BigUint {
     type Output = BigUint;
     /// Implements the Schonhage-Strassen algorithm of Multiplication.
-    /// Uses the Fast Fourier Transform to convole the two numbers and then
-    /// inverse Fast Fourier Transform them back. Applies necessary carrying,
-    /// and then returns the result of the multiplication. 
-    fn mul(self, rhs: &'b BigUint) -> BigUint {
-        let mut lhs = self.buckets.clone();
-        let mut rhs = rhs.buckets.clone();
-
-        let (omega, modulus, mut convolution) = fft_convolution(&mut lhs, &mut rhs).unwrap();
-        ifft(omega, modulus, &mut convolution).unwrap();
-        apply_carries(&mut convolution);
-
-        BigUint { buckets: convolution }
+    /// See `BigUint::checked_mul` for the details.
+    ///
+    /// # Panics
+    /// Panics if the transform fails, e.g. if either operand is empty
+    /// or the operands are too large for an i64 modulus.
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        match self.checked_mul(rhs) {
+            Ok(product) => product,
+            Err(error) => panic!("[NttError]: {}", error),
+        }
     }
 }
 
 /// Applies any carrying in all buckets that may be over capacity.
 pub fn apply_carries(buckets: &mut Vec<i64>) {
     let mut carry = 0;
-    println!("buckets before = {:?}", buckets);
     for bucket in buckets.iter_mut() {
         *bucket += carry;
         carry = *bucket / BUCKET_CAP;
@@ -665,5 +1550,4 @@ pub fn apply_carries(buckets: &mut Vec<i64>) {
         buckets.push(carry % 100);
         carry /= 100;
     }
-    println!("buckets after = {:?}", buckets);
-}
\ No newline at end of file
+}
@@ -0,0 +1,286 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! Bit-level access and the bitwise operators.
+//!
+//! A `BigUint` is stored in decimal buckets, so every bitwise operation first
+//! converts its operands to binary words, least significant first, works on those,
+//! and converts back. Shifts are the exception: they are multiplications and
+//! divisions by powers of two and never leave the decimal representation, and
+//! neither do `bit` and `set_bit` for the bits below `SMALL_SHIFT`.
+
+use biguint::div_rem;
+use std::cmp::max;
+use std::convert::TryFrom;
+use std::f64::consts::LOG2_10;
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+use BigUint;
+
+/// Shifts up to this many bits are made sixteen bits at a time in place.
+/// Longer ones multiply or divide by a power of two computed with `pow`.
+const SMALL_SHIFT: usize = 64;
+
+impl BigUint {
+    /// Returns the number of bits needed to represent the number, which is zero for zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(0, BigUint::zero().bits());
+    /// assert_eq!(10, BigUint::new("1,023").bits());
+    /// assert_eq!(11, BigUint::new("1,024").bits());
+    /// ```
+    pub fn bits(&self) -> u64 {
+        let words = self.to_u64_digits();
+        match words.last() {
+            Some(top) => 64 * words.len() as u64 - u64::from(top.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    /// Returns the number of set bits in the binary representation.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(10, BigUint::new("1,023").count_ones());
+    /// ```
+    pub fn count_ones(&self) -> u64 {
+        self.to_u64_digits().iter().map(|word| u64::from(word.count_ones())).sum()
+    }
+
+    /// Returns the number of trailing zero bits, i.e. how many times two divides
+    /// the number, or `None` for zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(Some(10), BigUint::new("1,024").trailing_zeros());
+    /// assert_eq!(None, BigUint::zero().trailing_zeros());
+    /// ```
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let words = self.to_u64_digits();
+        let index = words.iter().position(|word| 0 != *word)?;
+        Some(64 * index as u64 + u64::from(words[index].trailing_zeros()))
+    }
+
+    /// Returns whether the bit with value `2^bit` is set. The low bits are shifted
+    /// down to the bottom in decimal, while higher ones need the whole number in binary,
+    /// so to read many bits, convert once with `to_u64_digits` and index the words.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let number = BigUint::new("1,024");
+    /// assert!(number.bit(10));
+    /// assert!(!number.bit(9));
+    /// assert!(!number.bit(1_000));
+    /// ```
+    pub fn bit(&self, bit: u64) -> bool {
+        if bit < SMALL_SHIFT as u64 {
+            // A pass of short division per sixteen bits, without leaving decimal.
+            return !self.shr_bits(bit as usize).is_even();
+        }
+        let words = self.to_u64_digits();
+        match usize::try_from(bit / 64).ok().and_then(|index| words.get(index)) {
+            Some(word) => 1 == (word >> (bit % 64)) & 1,
+            None => false,
+        }
+    }
+
+    /// Sets or clears the bit with value `2^bit`, by adding or subtracting that power of two.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let mut number = BigUint::new("1,024");
+    /// number.set_bit(0, true);
+    /// number.set_bit(10, false);
+    /// assert_eq!(BigUint::one(), number);
+    /// ```
+    pub fn set_bit(&mut self, bit: u64, value: bool) {
+        // Every bucket holds fewer than `2 * log2(10)` bits, so a bit above that many
+        // is known to be clear without the conversion to binary that `bit` may need.
+        let above_top = bit as f64 > self.bucket_len() as f64 * 2.0 * LOG2_10;
+        if value == (!above_top && self.bit(bit)) {
+            return;
+        }
+        let power = power_of_two(usize::try_from(bit).expect("bit index is too large"));
+        *self = self.trimmed();
+        if value {
+            *self += &power;
+        } else {
+            *self -= &power;
+        }
+    }
+
+    /// Returns the binary digits of the number, least significant first.
+    pub(crate) fn to_bits(&self) -> Vec<bool> {
+        let mut bits: Vec<bool> = self
+            .to_u64_digits()
+            .iter()
+            .flat_map(|word| (0..64).map(move |bit| 1 == (word >> bit) & 1))
+            .collect();
+        while bits.last() == Some(&false) {
+            bits.pop();
+        }
+        bits
+    }
+
+    /// Converts to 64-bit words, least significant first, without any high-order
+    /// zero words. Each word is peeled off as two halves by short division, so the
+    /// conversion is quadratic in the length of the number: convert once and reuse
+    /// the words rather than calling `bit` for every index.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(vec![0, 1], BigUint::new("18,446,744,073,709,551,616").to_u64_digits());
+    /// assert!(BigUint::zero().to_u64_digits().is_empty());
+    /// ```
+    pub fn to_u64_digits(&self) -> Vec<u64> {
+        const HALF: i64 = 1 << 32;
+        let mut words = Vec::new();
+        let mut number = self.trimmed();
+        while !number.is_zero() {
+            let (quotient, low) = number.div_rem_small(HALF);
+            let (quotient, high) = quotient.div_rem_small(HALF);
+            words.push((high as u64) << 32 | low as u64);
+            number = quotient;
+        }
+        words
+    }
+
+    /// Converts from 64-bit words, least significant first, with Horner's method.
+    pub(crate) fn from_words(words: &[u64]) -> BigUint {
+        let mut number = BigUint::zero();
+        for word in words.iter().rev() {
+            for half in &[word >> 32, word & 0xffff_ffff] {
+                number *= 1_u32 << 16;
+                number *= 1_u32 << 16;
+                number += *half as u32;
+            }
+        }
+        number
+    }
+
+    /// Divides the number by `2^shift`, discarding the remainder.
+    pub(crate) fn shr_bits(&self, mut shift: usize) -> BigUint {
+        let mut number = self.trimmed();
+        while 0 < shift && !number.is_zero() {
+            let step = shift.min(16);
+            number = number.div_rem_small(1 << step).0;
+            shift -= step;
+        }
+        number
+    }
+
+    /// Multiplies the number by `2^shift`.
+    fn shl_bits(&self, mut shift: usize) -> BigUint {
+        let mut number = self.trimmed();
+        while 0 < shift {
+            let step = shift.min(16);
+            number *= 1_u32 << step;
+            shift -= step;
+        }
+        number
+    }
+}
+
+/// Calculates `2^exponent`.
+fn power_of_two(exponent: usize) -> BigUint {
+    BigUint::from(2_u32).pow(u32::try_from(exponent).expect("shift is too large"))
+}
+
+/// Combines the binary words of two numbers one pair at a time,
+/// treating the missing words of the shorter number as zero.
+fn combine_words(lhs: &BigUint, rhs: &BigUint, combine: fn(u64, u64) -> u64) -> BigUint {
+    let (lhs, rhs) = (lhs.to_u64_digits(), rhs.to_u64_digits());
+    let words: Vec<u64> = (0..max(lhs.len(), rhs.len()))
+        .map(|index| {
+            let word = |words: &[u64]| words.get(index).cloned().unwrap_or(0);
+            combine(word(&lhs), word(&rhs))
+        })
+        .collect();
+    BigUint::from_words(&words)
+}
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+    /// Multiplies by `2^rhs`.
+    fn shl(self, rhs: usize) -> BigUint {
+        if rhs <= SMALL_SHIFT {
+            self.shl_bits(rhs)
+        } else {
+            &self.trimmed() * &power_of_two(rhs)
+        }
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+    /// Divides by `2^rhs`, rounding down.
+    fn shr(self, rhs: usize) -> BigUint {
+        if rhs <= SMALL_SHIFT {
+            self.shr_bits(rhs)
+        } else if rhs as f64 > self.bucket_len() as f64 * 2.0 * LOG2_10 {
+            // Every bucket holds fewer than `2 * log2(10)` bits.
+            BigUint::zero()
+        } else {
+            div_rem(self, &power_of_two(rhs)).0
+        }
+    }
+}
+
+impl BitAnd<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn bitand(self, rhs: &BigUint) -> BigUint {
+        combine_words(self, rhs, |lhs, rhs| lhs & rhs)
+    }
+}
+
+impl BitOr<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn bitor(self, rhs: &BigUint) -> BigUint {
+        combine_words(self, rhs, |lhs, rhs| lhs | rhs)
+    }
+}
+
+impl BitXor<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn bitxor(self, rhs: &BigUint) -> BigUint {
+        combine_words(self, rhs, |lhs, rhs| lhs ^ rhs)
+    }
+}
+
+impl Not for &BigUint {
+    type Output = BigUint;
+    /// Flips every bit below the highest set bit. A `BigUint` has no fixed width,
+    /// so the complement is taken within `bits()` bits, and the complement of zero is zero.
+    fn not(self) -> BigUint {
+        let mut words: Vec<u64> = self.to_u64_digits().iter().map(|word| !word).collect();
+        if let Some(top) = words.last_mut() {
+            let width = 64 - (!*top).leading_zeros();
+            if width < 64 {
+                *top &= (1 << width) - 1;
+            }
+        }
+        BigUint::from_words(&words)
+    }
+}
+
+impl Not for BigUint {
+    type Output = BigUint;
+    fn not(self) -> BigUint {
+        !&self
+    }
+}
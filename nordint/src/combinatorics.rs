@@ -0,0 +1,385 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! Factorials and the counting functions built on them.
+//!
+//! Quotients of factorials, such as binomial and Catalan numbers, are never
+//! computed by dividing. Legendre's formula gives the exponent of every prime
+//! in each factorial, so the quotient's prime factorization is a difference of
+//! exponents, and `prime_power_product` multiplies it out with balanced operands.
+//! `stirling2`, `bell` and `partition_count` are sums instead, built from those
+//! products or from their recurrences.
+
+use biguint::{div_rem, product_tree};
+use prime::primes_below;
+use BigUint;
+
+/// Every factorial below this fits in a u64, so it is computed directly.
+const SMALL_FACTORIAL_LIMIT: u64 = 21;
+
+impl BigUint {
+    /// Calculates the factorial of a given number with Luschny's prime-swing algorithm.
+    ///
+    /// The swinging factorial `n≀ = n! / (n/2)!^2` is a product of prime powers whose
+    /// exponents are the parities of the terms of Legendre's formula, so
+    /// `n! = (n/2)!^2 n≀` costs one squaring and one product tree per halving of `n`.
+    /// That keeps the operands of the fast multiplier balanced, rather than
+    /// multiplying one small number at a time into the accumulator.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("3,628,800"), BigUint::fac(10));
+    /// assert_eq!(BigUint::one(), BigUint::fac(0));
+    /// ```
+    pub fn fac(n: u32) -> BigUint {
+        let primes = primes_below(n as usize + 1);
+        factorial(u64::from(n), &primes)
+    }
+
+    /// Calculates the double factorial `n!! = n (n - 2) (n - 4) ...`, down to 1 or 2.
+    ///
+    /// For even `n = 2k` this is `2^k k!`. For odd `n = 2k + 1` it is `(2k + 1)! / (2^k k!)`,
+    /// which is built from its prime factorization rather than by dividing.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("3,840"), BigUint::double_factorial(10));
+    /// assert_eq!(BigUint::new("10,395"), BigUint::double_factorial(11));
+    /// ```
+    pub fn double_factorial(n: u32) -> BigUint {
+        if n < 2 {
+            return BigUint::one();
+        }
+        let k = n / 2;
+        if n.is_multiple_of(2) {
+            return &BigUint::fac(k) * &BigUint::from(2_u32).pow(k);
+        }
+        let (n, k) = (u64::from(n), u64::from(k));
+        let factors = primes_below(n as usize + 1)
+            .into_iter()
+            .skip(1)
+            .map(|prime| (prime, legendre(n, prime) - legendre(k, prime)))
+            .collect::<Vec<(u64, u64)>>();
+        prime_power_product(&factors)
+    }
+
+    /// Calculates the primorial `n#`, the product of every prime up to `n`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("30,030"), BigUint::primorial(13));
+    /// assert_eq!(BigUint::new("30,030"), BigUint::primorial(16));
+    /// ```
+    pub fn primorial(n: u32) -> BigUint {
+        product_of_words(primes_below(n as usize + 1))
+    }
+
+    /// Calculates the falling factorial `x (x - 1) ... (x - k + 1)` of this number,
+    /// which is zero whenever `k` exceeds the number.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("720"), BigUint::new("10").falling_factorial(3));
+    /// assert_eq!(BigUint::zero(), BigUint::new("2").falling_factorial(3));
+    /// ```
+    pub fn falling_factorial(&self, k: u32) -> BigUint {
+        if 0 == k {
+            return BigUint::one();
+        }
+        match self.to_u64() {
+            Some(number) if number < u64::from(k) => BigUint::zero(),
+            Some(number) => product_of_words(number - u64::from(k) + 1..=number),
+            None => BigUint::consecutive_product(self - &BigUint::from(k - 1), k),
+        }
+    }
+
+    /// Calculates the rising factorial `x (x + 1) ... (x + k - 1)` of this number.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("1,320"), BigUint::new("10").rising_factorial(3));
+    /// assert_eq!(BigUint::fac(7), BigUint::one().rising_factorial(7));
+    /// ```
+    pub fn rising_factorial(&self, k: u32) -> BigUint {
+        if 0 == k {
+            return BigUint::one();
+        }
+        if let Some(number) = self.to_u64() {
+            if let Some(last) = number.checked_add(u64::from(k) - 1) {
+                return product_of_words(number..=last);
+            }
+        }
+        BigUint::consecutive_product(self.trimmed(), k)
+    }
+
+    /// Calculates the binomial coefficient `C(n, k) = n! / (k! (n - k)!)`.
+    ///
+    /// By Legendre's formula the exponent of each prime is known without
+    /// dividing anything, so the coefficient is built directly from its prime
+    /// factorization. That stays fast for `n` in the millions.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("252"), BigUint::binomial(10, 5));
+    /// assert_eq!(BigUint::new("100,891,344,545,564,193,334,812,497,256"), BigUint::binomial(100, 50));
+    /// assert_eq!(BigUint::zero(), BigUint::binomial(5, 6));
+    /// ```
+    pub fn binomial(n: u32, k: u32) -> BigUint {
+        if k > n {
+            return BigUint::zero();
+        }
+        let (n, k) = (u64::from(n), u64::from(k));
+        prime_power_product(&quotient_exponents(n, &[k, n - k]))
+    }
+
+    /// Calculates the multinomial coefficient `(k_1 + k_2 + ...)! / (k_1! k_2! ...)`,
+    /// the number of ways to split a set into parts of the given sizes.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// // The arrangements of the letters of MISSISSIPPI.
+    /// assert_eq!(BigUint::new("34,650"), BigUint::multinomial(&[1, 4, 4, 2]));
+    /// assert_eq!(BigUint::one(), BigUint::multinomial(&[]));
+    /// ```
+    pub fn multinomial(parts: &[u32]) -> BigUint {
+        let parts = parts.iter().map(|part| u64::from(*part)).collect::<Vec<u64>>();
+        prime_power_product(&quotient_exponents(parts.iter().sum(), &parts))
+    }
+
+    /// Calculates the nth Catalan number `C(2n, n) / (n + 1)`, from its prime factorization.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("16,796"), BigUint::catalan(10));
+    /// ```
+    pub fn catalan(n: u32) -> BigUint {
+        let n = u64::from(n);
+        let mut factors = quotient_exponents(2 * n, &[n, n]);
+        for (prime, exponent) in factors.iter_mut() {
+            let mut remaining = n + 1;
+            while 0 == remaining % *prime {
+                remaining /= *prime;
+                *exponent -= 1;
+            }
+        }
+        prime_power_product(&factors)
+    }
+
+    /// Calculates the Stirling number of the second kind `S(n, k)`, the number of ways
+    /// to partition a set of `n` elements into `k` non-empty subsets, from the explicit
+    /// formula `k! S(n, k) = sum (-1)^(k - j) C(k, j) j^n`.
+    ///
+    /// The positive and negative terms are summed separately, and the only division
+    /// is the final, exact one by `k!`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("350"), BigUint::stirling2(7, 4));
+    /// assert_eq!(BigUint::zero(), BigUint::stirling2(3, 4));
+    /// ```
+    pub fn stirling2(n: u32, k: u32) -> BigUint {
+        if k > n || (0 == k && 0 < n) {
+            return BigUint::zero();
+        }
+        if 0 == k {
+            return BigUint::one();
+        }
+        // The j = 0 term vanishes, since n is positive.
+        let mut positive = BigUint::zero();
+        let mut negative = BigUint::zero();
+        for j in 1..=k {
+            let term = &BigUint::binomial(k, j) * &BigUint::from(j).pow(n);
+            if (k - j).is_multiple_of(2) {
+                positive += &term;
+            } else {
+                negative += &term;
+            }
+        }
+        div_rem(&(&positive - &negative), &BigUint::fac(k)).0
+    }
+
+    /// Calculates the nth Bell number, the number of partitions of a set of `n` elements,
+    /// with the Bell triangle: each row starts with the last entry of the row above,
+    /// and each further entry adds the entry above it. Only additions are needed.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("115,975"), BigUint::bell(10));
+    /// ```
+    pub fn bell(n: u32) -> BigUint {
+        let mut row = vec![BigUint::one()];
+        for _ in 0..n {
+            let mut next = Vec::with_capacity(row.len() + 1);
+            let mut entry = row[row.len() - 1].clone();
+            for above in &row {
+                next.push(entry.clone());
+                entry += above;
+            }
+            next.push(entry);
+            row = next;
+        }
+        row.swap_remove(0)
+    }
+
+    /// Calculates the partition function `p(n)`, the number of ways to write `n`
+    /// as a sum of positive integers, with Euler's pentagonal number recurrence
+    /// `p(n) = sum (-1)^(k + 1) (p(n - k (3k - 1) / 2) + p(n - k (3k + 1) / 2))`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("190,569,292"), BigUint::partition_count(100));
+    /// ```
+    pub fn partition_count(n: u32) -> BigUint {
+        let n = n as usize;
+        let mut partitions = Vec::with_capacity(n + 1);
+        partitions.push(BigUint::one());
+        for m in 1..=n {
+            let mut positive = BigUint::zero();
+            let mut negative = BigUint::zero();
+            for k in 1.. {
+                let pentagonal = k * (3 * k - 1) / 2;
+                if pentagonal > m {
+                    break;
+                }
+                let sum = if 1 == k % 2 { &mut positive } else { &mut negative };
+                *sum += &partitions[m - pentagonal];
+                if pentagonal + k <= m {
+                    *sum += &partitions[m - pentagonal - k];
+                }
+            }
+            partitions.push(&positive - &negative);
+        }
+        partitions.swap_remove(n)
+    }
+
+    /// Multiplies the `k` consecutive numbers starting at `first` with a product tree.
+    fn consecutive_product(first: BigUint, k: u32) -> BigUint {
+        let mut factors = Vec::with_capacity(k as usize);
+        let mut factor = first;
+        for _ in 1..k {
+            let mut next = factor.clone();
+            next += &BigUint::one();
+            factors.push(factor);
+            factor = next;
+        }
+        factors.push(factor);
+        product_tree(&factors)
+    }
+}
+
+/// Calculates `n!` as `(n/2)!^2` times the swinging factorial of `n`.
+fn factorial(n: u64, primes: &[u64]) -> BigUint {
+    if n < SMALL_FACTORIAL_LIMIT {
+        return BigUint::from((1..=n).product::<u64>());
+    }
+    let half = factorial(n / 2, primes);
+    &(&half * &half) * &swing(n, primes)
+}
+
+/// Calculates the swinging factorial `n! / (n/2)!^2`. A prime `p` appears in it once
+/// for every `k` where `n / p^k` is odd.
+fn swing(n: u64, primes: &[u64]) -> BigUint {
+    let factors = primes
+        .iter()
+        .take_while(|prime| **prime <= n)
+        .map(|&prime| {
+            let mut exponent = 0;
+            let mut quotient = n / prime;
+            while 0 < quotient {
+                exponent += quotient & 1;
+                quotient /= prime;
+            }
+            (prime, exponent)
+        })
+        .collect::<Vec<(u64, u64)>>();
+    prime_power_product(&factors)
+}
+
+/// Legendre's formula: the exponent of the prime `p` in `n!` is the sum of `n / p^k`.
+pub(crate) fn legendre(n: u64, p: u64) -> u64 {
+    let mut exponent = 0;
+    let mut quotient = n / p;
+    while 0 < quotient {
+        exponent += quotient;
+        quotient /= p;
+    }
+    exponent
+}
+
+/// Finds the prime factorization of `n! / (k_1! k_2! ...)` with Legendre's formula,
+/// for denominators that divide the numerator.
+fn quotient_exponents(n: u64, denominators: &[u64]) -> Vec<(u64, u64)> {
+    primes_below(n as usize + 1)
+        .into_iter()
+        .map(|prime| {
+            let denominator = denominators.iter().map(|k| legendre(*k, prime)).sum::<u64>();
+            (prime, legendre(n, prime) - denominator)
+        })
+        .collect()
+}
+
+/// Multiplies out a list of `(prime, exponent)` pairs. Writing every exponent as
+/// `2 (e / 2) + (e % 2)` turns the product into a square of a product with half
+/// the exponents, times a product of distinct primes, so each prime is multiplied
+/// in only once per level and most of the work is in a few large squarings.
+pub(crate) fn prime_power_product(factors: &[(u64, u64)]) -> BigUint {
+    let halves = factors
+        .iter()
+        .filter(|(_, exponent)| 1 < *exponent)
+        .map(|&(prime, exponent)| (prime, exponent / 2))
+        .collect::<Vec<(u64, u64)>>();
+    let odd = product_of_words(
+        factors
+            .iter()
+            .filter(|(_, exponent)| 1 == exponent % 2)
+            .map(|&(prime, _)| prime),
+    );
+    if halves.is_empty() {
+        return odd;
+    }
+    let half = prime_power_product(&halves);
+    &(&half * &half) * &odd
+}
+
+/// Packs small factors into as few u64 words as possible, then multiplies the words
+/// with a product tree.
+pub(crate) fn product_of_words<I: IntoIterator<Item = u64>>(factors: I) -> BigUint {
+    let mut words = Vec::new();
+    let mut word = 1_u64;
+    for factor in factors {
+        match word.checked_mul(factor) {
+            Some(product) => word = product,
+            None => {
+                words.push(BigUint::from(word));
+                word = factor;
+            }
+        }
+    }
+    words.push(BigUint::from(word));
+    product_tree(&words)
+}
@@ -0,0 +1,396 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! Exact decimal numbers with a fixed number of digits after the point.
+//!
+//! A `BigUint` already stores its value in base 100, so scaling by a power of
+//! ten only moves buckets around. A `BigDecimal` is an unscaled integer
+//! together with the number of decimal digits that sit after the point.
+
+use biguint::div_rem;
+use rational::signed_add;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use {BigUint, ParseBigIntError};
+
+/// How to round when digits have to be dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, with halves rounded to the even neighbour.
+    HalfEven,
+    /// Rounds to the nearest value, with halves rounded away from zero.
+    HalfUp,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceiling,
+    /// Rounds towards zero.
+    Truncate,
+}
+
+/// An exact decimal number of arbitrary size.
+///
+/// The value is `unscaled * 10^-scale`, stored as a sign and the magnitude of
+/// the unscaled integer. The scale is the number of digits after the point and
+/// may be negative. Adding, subtracting and multiplying are exact, and division
+/// rounds to a requested scale.
+///
+/// Values compare numerically, so `1.0` and `1.00` are equal even though their
+/// scales differ.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// let price: BigDecimal = "19.99".parse().unwrap();
+/// let quantity: BigDecimal = "3".parse().unwrap();
+/// assert_eq!("59.97", (&price * &quantity).to_string());
+/// let third = price.div_rounded(&quantity, 4, RoundingMode::HalfEven);
+/// assert_eq!("6.6633", third.to_string());
+/// ```
+#[derive(Clone, Debug)]
+pub struct BigDecimal {
+    negative: bool,
+    unscaled: BigUint,
+    scale: i64,
+}
+
+impl BigDecimal {
+    /// Creates the non-negative number `unscaled * 10^-scale`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!("1.2345", BigDecimal::new(BigUint::new("12,345"), 4).to_string());
+    /// assert_eq!("1200", BigDecimal::new(BigUint::new("12"), -2).to_string());
+    /// ```
+    pub fn new(unscaled: BigUint, scale: i64) -> BigDecimal {
+        BigDecimal::signed(false, unscaled, scale)
+    }
+
+    /// Creates the decimal zero, with a scale of zero.
+    pub fn zero() -> BigDecimal {
+        BigDecimal::from(BigUint::zero())
+    }
+
+    /// Creates the decimal one, with a scale of zero.
+    pub fn one() -> BigDecimal {
+        BigDecimal::from(BigUint::one())
+    }
+
+    /// Returns the magnitude of the unscaled integer.
+    pub fn unscaled(&self) -> &BigUint {
+        &self.unscaled
+    }
+
+    /// Returns the number of digits after the point, which is negative if
+    /// the unscaled integer is multiplied by a power of ten.
+    pub fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    /// Returns true if the value is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns true if the value is zero, whatever the scale.
+    pub fn is_zero(&self) -> bool {
+        self.unscaled.is_zero()
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> BigDecimal {
+        BigDecimal {
+            negative: false,
+            ..self.clone()
+        }
+    }
+
+    /// Removes trailing zeros from the unscaled integer, lowering the scale to match.
+    /// Zero is normalized to a scale of zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let value: BigDecimal = "1.2300".parse().unwrap();
+    /// assert_eq!("1.23", value.normalized().to_string());
+    /// let value: BigDecimal = "1200".parse().unwrap();
+    /// assert_eq!(-2, value.normalized().scale());
+    /// ```
+    pub fn normalized(&self) -> BigDecimal {
+        if self.is_zero() {
+            return BigDecimal::zero();
+        }
+        let zeros = self.unscaled.digits().rev().take_while(|digit| 0 == *digit).count();
+        BigDecimal {
+            negative: self.negative,
+            unscaled: self.unscaled.shr_digits(zeros),
+            scale: self.scale - zeros as i64,
+        }
+    }
+
+    /// Changes the scale, rounding with `mode` if digits have to be dropped.
+    /// Raising the scale only appends zeros and is always exact.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let value: BigDecimal = "-2.345".parse().unwrap();
+    /// assert_eq!("-2.34", value.with_scale(2, RoundingMode::HalfEven).to_string());
+    /// assert_eq!("-2.35", value.with_scale(2, RoundingMode::HalfUp).to_string());
+    /// assert_eq!("-2.35", value.with_scale(2, RoundingMode::Floor).to_string());
+    /// assert_eq!("-2.34", value.with_scale(2, RoundingMode::Ceiling).to_string());
+    /// assert_eq!("-2.3450", value.with_scale(4, RoundingMode::Truncate).to_string());
+    /// ```
+    pub fn with_scale(&self, scale: i64, mode: RoundingMode) -> BigDecimal {
+        let unscaled = if self.scale <= scale {
+            self.unscaled.shl_digits((scale - self.scale) as usize)
+        } else {
+            let divisor = BigUint::pow_of_ten((self.scale - scale) as usize);
+            rounded_quotient(self.negative, &self.unscaled, &divisor, mode)
+        };
+        BigDecimal::signed(self.negative, unscaled, scale)
+    }
+
+    /// Divides by `divisor`, keeping `scale` digits after the point and rounding
+    /// the rest with `mode`.
+    ///
+    /// # Panics
+    /// Panics if the divisor is zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let two: BigDecimal = "2".parse().unwrap();
+    /// let three: BigDecimal = "3".parse().unwrap();
+    /// assert_eq!("0.666666", two.div_rounded(&three, 6, RoundingMode::Truncate).to_string());
+    /// assert_eq!("0.666667", two.div_rounded(&three, 6, RoundingMode::HalfEven).to_string());
+    /// assert_eq!("0", two.div_rounded(&three, -1, RoundingMode::HalfEven).to_string());
+    /// ```
+    pub fn div_rounded(&self, divisor: &BigDecimal, scale: i64, mode: RoundingMode) -> BigDecimal {
+        assert!(!divisor.is_zero(), "attempt to divide a BigDecimal by zero");
+        // The quotient of the unscaled integers has a scale of `self.scale - divisor.scale`,
+        // so the dividend is shifted left by however many digits are still missing.
+        let shift = scale - self.scale + divisor.scale;
+        let negative = self.negative != divisor.negative;
+        let unscaled = if 0 <= shift {
+            rounded_quotient(negative, &self.unscaled.shl_digits(shift as usize), &divisor.unscaled, mode)
+        } else {
+            rounded_quotient(negative, &self.unscaled, &divisor.unscaled.shl_digits((-shift) as usize), mode)
+        };
+        BigDecimal::signed(negative, unscaled, scale)
+    }
+
+    /// Wraps a signed magnitude, keeping zero non-negative.
+    fn signed(negative: bool, unscaled: BigUint, scale: i64) -> BigDecimal {
+        let unscaled = unscaled.trimmed();
+        BigDecimal {
+            negative: negative && !unscaled.is_zero(),
+            unscaled,
+            scale,
+        }
+    }
+
+    /// Returns the unscaled integer shifted to the larger `scale`.
+    fn unscaled_at(&self, scale: i64) -> BigUint {
+        self.unscaled.shl_digits((scale - self.scale) as usize)
+    }
+}
+
+/// Divides `numerator` by `denominator` and rounds the quotient of the signed value with `mode`.
+fn rounded_quotient(negative: bool, numerator: &BigUint, denominator: &BigUint, mode: RoundingMode) -> BigUint {
+    let (quotient, remainder) = div_rem(numerator, denominator);
+    if remainder.is_zero() {
+        return quotient;
+    }
+    let round_away = match mode {
+        RoundingMode::Truncate => false,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceiling => !negative,
+        RoundingMode::HalfUp => &remainder * 2_u8 >= *denominator,
+        RoundingMode::HalfEven => match (&remainder * 2_u8).cmp(denominator) {
+            Ordering::Less => false,
+            Ordering::Equal => !quotient.is_even(),
+            Ordering::Greater => true,
+        },
+    };
+    if round_away {
+        quotient + 1_u8
+    } else {
+        quotient
+    }
+}
+
+impl Default for BigDecimal {
+    fn default() -> BigDecimal {
+        BigDecimal::zero()
+    }
+}
+
+impl From<BigUint> for BigDecimal {
+    fn from(integer: BigUint) -> BigDecimal {
+        BigDecimal::new(integer, 0)
+    }
+}
+
+impl From<u64> for BigDecimal {
+    fn from(integer: u64) -> BigDecimal {
+        BigDecimal::from(BigUint::from(integer))
+    }
+}
+
+impl From<i64> for BigDecimal {
+    fn from(integer: i64) -> BigDecimal {
+        BigDecimal::signed(integer < 0, BigUint::from(integer.unsigned_abs()), 0)
+    }
+}
+
+impl Ord for BigDecimal {
+    /// Compares by sign, and then by the magnitudes brought to a common scale.
+    fn cmp(&self, other: &BigDecimal) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (negative, _) => {
+                let scale = self.scale.max(other.scale);
+                let ordering = self.unscaled_at(scale).cmp(&other.unscaled_at(scale));
+                if negative {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &BigDecimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for BigDecimal {
+    /// Compares numerically, ignoring the scale.
+    fn eq(&self, other: &BigDecimal) -> bool {
+        Ordering::Equal == self.cmp(other)
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl fmt::Display for BigDecimal {
+    /// Formats in plain notation, with exactly `scale` digits after the point,
+    /// or with `-scale` zeros appended when the scale is negative.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        let digits = self.unscaled.to_string();
+        if self.scale <= 0 {
+            let zeros = if self.is_zero() { 0 } else { (-self.scale) as usize };
+            return write!(f, "{}{}{}", sign, digits, "0".repeat(zeros));
+        }
+        let scale = self.scale as usize;
+        let padded = format!("{}{}", "0".repeat((scale + 1).saturating_sub(digits.len())), digits);
+        let point = padded.len() - scale;
+        write!(f, "{}{}.{}", sign, &padded[..point], &padded[point..])
+    }
+}
+
+impl FromStr for BigDecimal {
+    type Err = ParseBigIntError;
+
+    /// Parses a decimal such as `-12.50`, optionally followed by a power of ten
+    /// such as `123.4500e-7`. Every digit written is kept, so the scale is the
+    /// number of digits after the point minus the exponent.
+    fn from_str(decimal: &str) -> Result<BigDecimal, ParseBigIntError> {
+        if decimal.is_empty() {
+            return Err(ParseBigIntError::empty());
+        }
+        let (negative, unsigned) = match decimal.as_bytes().first() {
+            Some(b'-') => (true, &decimal[1..]),
+            Some(b'+') => (false, &decimal[1..]),
+            _ => (false, decimal),
+        };
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(e) => {
+                let exponent = unsigned[e + 1..].parse::<i64>().map_err(|_| ParseBigIntError::invalid())?;
+                (&unsigned[..e], exponent)
+            }
+            None => (unsigned, 0),
+        };
+        let (integer, fraction) = match mantissa.find('.') {
+            Some(point) => (&mantissa[..point], &mantissa[point + 1..]),
+            None => (mantissa, ""),
+        };
+        let digits_only = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        let missing_digits = integer.is_empty() && fraction.is_empty() || mantissa.ends_with('.');
+        if missing_digits || !digits_only(integer) || !digits_only(fraction) {
+            return Err(ParseBigIntError::invalid());
+        }
+        let unscaled = BigUint::from_str(&format!("{}{}", integer, fraction))?;
+        let scale = (fraction.len() as i64)
+            .checked_sub(exponent)
+            .ok_or_else(ParseBigIntError::invalid)?;
+        Ok(BigDecimal::signed(negative, unscaled, scale))
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = BigDecimal;
+    fn neg(self) -> BigDecimal {
+        BigDecimal {
+            negative: !self.negative && !self.is_zero(),
+            ..self
+        }
+    }
+}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+    fn neg(self) -> BigDecimal {
+        -self.clone()
+    }
+}
+
+impl Add<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+    /// The sum keeps the larger of the two scales.
+    fn add(self, rhs: &BigDecimal) -> BigDecimal {
+        let scale = self.scale.max(rhs.scale);
+        let (negative, unscaled) = signed_add(self.negative, self.unscaled_at(scale), rhs.negative, rhs.unscaled_at(scale));
+        BigDecimal::signed(negative, unscaled, scale)
+    }
+}
+
+impl Sub<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+    fn sub(self, rhs: &BigDecimal) -> BigDecimal {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+    /// The product's scale is the sum of the two scales.
+    fn mul(self, rhs: &BigDecimal) -> BigDecimal {
+        BigDecimal::signed(
+            self.negative != rhs.negative,
+            &self.unscaled * &rhs.unscaled,
+            self.scale + rhs.scale,
+        )
+    }
+}
+
+forward_to_borrowed!(BigDecimal, Add, add, AddAssign, add_assign);
+forward_to_borrowed!(BigDecimal, Sub, sub, SubAssign, sub_assign);
+forward_to_borrowed!(BigDecimal, Mul, mul, MulAssign, mul_assign);
@@ -0,0 +1,245 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! Access to the decimal digits of a `BigUint`.
+//!
+//! Each bucket holds `DIGITS_PER_BUCKET` decimal digits, so a digit is read
+//! straight out of its bucket, and shifting by a power of ten moves whole
+//! buckets and only multiplies or divides by what is left over.
+
+use biguint::DIGITS_PER_BUCKET;
+use std::cmp::min;
+use std::iter::FusedIterator;
+use BigUint;
+
+/// An iterator over the decimal digits of a `BigUint`, most significant first.
+/// Reverse it to walk the digits from the least significant end.
+///
+/// Created by `BigUint::digits`.
+#[derive(Clone, Debug)]
+pub struct Digits<'a> {
+    buckets: &'a [i64],
+    /// The digits in positions `low..high`, counted from the least significant
+    /// digit, are still to come. The iterator's front is the high end of this range.
+    low: usize,
+    high: usize,
+}
+
+impl<'a> Iterator for Digits<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.low == self.high {
+            return None;
+        }
+        self.high -= 1;
+        Some(bucket_digit(self.buckets, self.high))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.high - self.low;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Digits<'a> {
+    fn next_back(&mut self) -> Option<u8> {
+        if self.low == self.high {
+            return None;
+        }
+        self.low += 1;
+        Some(bucket_digit(self.buckets, self.low - 1))
+    }
+}
+
+impl<'a> ExactSizeIterator for Digits<'a> {}
+
+impl<'a> FusedIterator for Digits<'a> {}
+
+impl BigUint {
+    /// Returns the number of decimal digits. Zero has one digit and an empty number has none.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(5, BigUint::new("12,345").num_digits());
+    /// assert_eq!(1, BigUint::zero().num_digits());
+    /// ```
+    pub fn num_digits(&self) -> usize {
+        match self.bucket_len() {
+            0 if self.is_empty() => 0,
+            0 => 1,
+            len => {
+                let mut top = self.buckets()[len - 1];
+                let mut top_digits = 0;
+                while 0 < top {
+                    top /= 10;
+                    top_digits += 1;
+                }
+                DIGITS_PER_BUCKET * (len - 1) + top_digits
+            }
+        }
+    }
+
+    /// Returns the decimal digit worth `10^index`, which is zero past the end of the number.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let number = BigUint::new("12,345");
+    /// assert_eq!(5, number.digit(0));
+    /// assert_eq!(1, number.digit(4));
+    /// assert_eq!(0, number.digit(100));
+    /// ```
+    pub fn digit(&self, index: usize) -> u8 {
+        bucket_digit(self.buckets(), index)
+    }
+
+    /// Iterates over the decimal digits, most significant first.
+    /// Call `rev` on the iterator to start from the least significant digit.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let number = BigUint::new("12,345");
+    /// assert_eq!(vec![1, 2, 3, 4, 5], number.digits().collect::<Vec<_>>());
+    /// assert_eq!(vec![5, 4, 3, 2, 1], number.digits().rev().collect::<Vec<_>>());
+    /// ```
+    pub fn digits(&self) -> Digits<'_> {
+        Digits {
+            buckets: self.buckets(),
+            low: 0,
+            high: self.num_digits(),
+        }
+    }
+
+    /// Adds up the decimal digits.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// // 2^1000 has a digit sum of 1366.
+    /// assert_eq!(1_366, BigUint::new("2").pow(1_000).digit_sum());
+    /// ```
+    pub fn digit_sum(&self) -> u64 {
+        self.buckets()
+            .iter()
+            .map(|&bucket| {
+                let mut bucket = bucket as u64;
+                let mut sum = 0;
+                while 0 < bucket {
+                    sum += bucket % 10;
+                    bucket /= 10;
+                }
+                sum
+            })
+            .sum()
+    }
+
+    /// Multiplies by `10^n`. Whole buckets of zeros are inserted below the number,
+    /// and only the remaining factor below a bucket is multiplied in.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("12,345,000"), BigUint::new("12,345").shl_digits(3));
+    /// ```
+    pub fn shl_digits(&self, n: usize) -> BigUint {
+        let number = self.trimmed();
+        if number.is_zero() {
+            return number;
+        }
+        let mut buckets = vec![0; n / DIGITS_PER_BUCKET];
+        buckets.extend_from_slice(number.buckets());
+        let mut shifted = BigUint::from_buckets(buckets);
+        shifted *= 10_u32.pow((n % DIGITS_PER_BUCKET) as u32);
+        shifted
+    }
+
+    /// Divides by `10^n`, discarding the remainder. Whole buckets are dropped
+    /// from the bottom of the number, and only the remaining divisor below a
+    /// bucket takes a division.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("12"), BigUint::new("12,345").shr_digits(3));
+    /// assert_eq!(BigUint::zero(), BigUint::new("12,345").shr_digits(30));
+    /// ```
+    pub fn shr_digits(&self, n: usize) -> BigUint {
+        let buckets = self.buckets();
+        let skip = n / DIGITS_PER_BUCKET;
+        if buckets.len() <= skip {
+            return BigUint::zero();
+        }
+        let shifted = BigUint::from_buckets(buckets[skip..].to_vec());
+        shifted.div_rem_small(10_i64.pow((n % DIGITS_PER_BUCKET) as u32)).0
+    }
+
+    /// Keeps only the lowest `n` decimal digits, i.e. reduces the number modulo `10^n`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let mut number = BigUint::new("12,345");
+    /// number.truncate_digits(3);
+    /// assert_eq!(BigUint::new("345"), number);
+    /// ```
+    pub fn truncate_digits(&mut self, n: usize) {
+        let whole = n / DIGITS_PER_BUCKET;
+        let partial = n % DIGITS_PER_BUCKET;
+        let len = self.buckets().len();
+        let mut buckets = self.buckets()[..min(len, whole + min(partial, 1))].to_vec();
+        if 0 < partial && whole < len {
+            buckets[whole] %= 10_i64.pow(partial as u32);
+        }
+        *self = if buckets.is_empty() {
+            BigUint::zero()
+        } else {
+            BigUint::from_buckets(buckets)
+        };
+    }
+
+    /// Builds a number from its decimal digits, most significant first.
+    /// No digits make zero.
+    ///
+    /// # Panics
+    /// Panics if any digit is greater than nine.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("12,345"), BigUint::from_digits(&[1, 2, 3, 4, 5]));
+    /// assert_eq!(BigUint::new("45"), BigUint::from_digits(&[0, 0, 4, 5]));
+    /// ```
+    pub fn from_digits(digits: &[u8]) -> BigUint {
+        assert!(digits.iter().all(|digit| *digit < 10), "every digit must be less than ten");
+        if digits.is_empty() {
+            return BigUint::zero();
+        }
+        let buckets = digits
+            .rchunks(DIGITS_PER_BUCKET)
+            .map(|chunk| chunk.iter().fold(0, |bucket, digit| bucket * 10 + i64::from(*digit)))
+            .collect();
+        BigUint::from_buckets(buckets)
+    }
+}
+
+/// Reads the decimal digit worth `10^index` out of its bucket.
+fn bucket_digit(buckets: &[i64], index: usize) -> u8 {
+    match buckets.get(index / DIGITS_PER_BUCKET) {
+        Some(bucket) => (bucket / 10_i64.pow((index % DIGITS_PER_BUCKET) as u32) % 10) as u8,
+        None => 0,
+    }
+}
@@ -0,0 +1,360 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+use biguint::{div_rem, mul_mod};
+use prime::{self, primes_below};
+use BigUint;
+
+/// Trial division is performed by every prime below this bound
+/// before falling back to Pollard's rho.
+const TRIAL_DIVISION_BOUND: usize = 1_000;
+
+/// Number of steps Brent's variant takes between each gcd,
+/// accumulating the differences into a single product.
+const BRENT_BATCH: u64 = 128;
+
+/// The prime factorization of a number, stored as `(prime, exponent)` pairs
+/// in ascending order of the primes.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// let factorization = factorize(360);
+/// assert_eq!(&[(2, 3), (3, 2), (5, 1)], factorization.factors());
+/// assert_eq!(360, factorization.value());
+/// assert_eq!(96, factorization.totient());
+/// assert_eq!(24, factorization.divisors().len());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Factorization<T> {
+    factors: Vec<(T, u32)>,
+}
+
+impl<T: Ord> Factorization<T> {
+    /// Groups a list of prime factors, with repetition, into sorted `(prime, exponent)` pairs.
+    fn from_primes(mut primes: Vec<T>) -> Factorization<T> {
+        primes.sort();
+        let mut factors: Vec<(T, u32)> = Vec::new();
+        for prime in primes {
+            match factors.last_mut() {
+                Some(&mut (ref last, ref mut exponent)) if *last == prime => *exponent += 1,
+                _ => factors.push((prime, 1)),
+            }
+        }
+        Factorization { factors }
+    }
+}
+
+impl<T> Factorization<T> {
+    /// Returns the `(prime, exponent)` pairs in ascending order of the primes.
+    pub fn factors(&self) -> &[(T, u32)] {
+        &self.factors
+    }
+
+    /// Returns the distinct prime factors in ascending order.
+    pub fn primes(&self) -> Vec<&T> {
+        self.factors.iter().map(|(prime, _)| prime).collect()
+    }
+
+    /// Returns true if the factored number is itself prime.
+    pub fn is_prime(&self) -> bool {
+        1 == self.factors.len() && 1 == self.factors[0].1
+    }
+
+    /// Returns the number of divisors: the product of `exponent + 1` over every prime.
+    pub fn divisor_count(&self) -> u64 {
+        self.factors
+            .iter()
+            .map(|(_, exponent)| u64::from(*exponent) + 1)
+            .product()
+    }
+}
+
+impl Factorization<u64> {
+    /// Multiplies the factors back together.
+    pub fn value(&self) -> u64 {
+        self.factors
+            .iter()
+            .map(|&(prime, exponent)| prime.pow(exponent))
+            .product()
+    }
+
+    /// Euler's totient: the count of numbers up to the value that are coprime to it.
+    /// Calculated as the product of `p^(e - 1) * (p - 1)` over every prime power.
+    pub fn totient(&self) -> u64 {
+        self.factors
+            .iter()
+            .map(|&(prime, exponent)| prime.pow(exponent - 1) * (prime - 1))
+            .product()
+    }
+
+    /// Enumerates every divisor of the value in ascending order.
+    pub fn divisors(&self) -> Vec<u64> {
+        let mut divisors = vec![1];
+        for &(prime, exponent) in &self.factors {
+            let previous = divisors.len();
+            let mut power = 1;
+            for _ in 0..exponent {
+                power *= prime;
+                for index in 0..previous {
+                    divisors.push(divisors[index] * power);
+                }
+            }
+        }
+        divisors.sort();
+        divisors
+    }
+}
+
+impl Factorization<BigUint> {
+    /// Multiplies the factors back together.
+    pub fn value(&self) -> BigUint {
+        self.factors.iter().map(|(prime, exponent)| prime.pow(*exponent)).product()
+    }
+
+    /// Euler's totient: the count of numbers up to the value that are coprime to it.
+    /// Calculated as the product of `p^(e - 1) * (p - 1)` over every prime power.
+    pub fn totient(&self) -> BigUint {
+        self.factors
+            .iter()
+            .map(|(prime, exponent)| &prime.pow(exponent - 1) * &(prime - &BigUint::one()))
+            .product()
+    }
+
+    /// Enumerates every divisor of the value in ascending order.
+    pub fn divisors(&self) -> Vec<BigUint> {
+        let mut divisors = vec![BigUint::one()];
+        for (prime, exponent) in &self.factors {
+            let previous = divisors.len();
+            let mut power = BigUint::one();
+            for _ in 0..*exponent {
+                power = &power * prime;
+                for index in 0..previous {
+                    let divisor = &divisors[index] * &power;
+                    divisors.push(divisor);
+                }
+            }
+        }
+        divisors.sort();
+        divisors
+    }
+}
+
+/// Factors a machine word into primes.
+///
+/// Small primes are removed by trial division, and whatever remains is split
+/// with Brent's variant of Pollard's rho until every piece passes the
+/// deterministic Miller-Rabin test in `is_prime`.
+///
+/// # Panics
+/// There is no factorization of zero, so `factorize(0)` panics.
+/// The factorization of one is empty.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// // (2^31 - 1) * (2^31 - 1) * 3
+/// let factorization = factorize(13_835_058_042_397_261_827);
+/// assert_eq!(&[(3, 1), (2_147_483_647, 2)], factorization.factors());
+/// ```
+pub fn factorize(mut number: u64) -> Factorization<u64> {
+    assert!(0 != number, "cannot factorize zero");
+    let mut primes = Vec::new();
+    for prime in primes_below(TRIAL_DIVISION_BOUND) {
+        if prime * prime > number {
+            break;
+        }
+        while number.is_multiple_of(prime) {
+            primes.push(prime);
+            number /= prime;
+        }
+    }
+
+    let mut composites = vec![number];
+    while let Some(composite) = composites.pop() {
+        if 1 == composite {
+            continue;
+        }
+        if prime::is_prime(composite) {
+            primes.push(composite);
+            continue;
+        }
+        let divisor = pollard_brent(composite);
+        composites.push(divisor);
+        composites.push(composite / divisor);
+    }
+    Factorization::from_primes(primes)
+}
+
+/// Finds a non-trivial divisor of an odd composite using Brent's variant of Pollard's rho.
+/// The sequence `x -> x^2 + c (mod n)` eventually cycles modulo each prime factor of `n`;
+/// Brent's cycle detection finds that cycle, and a gcd with `n` exposes the factor.
+/// If a sequence happens to cycle modulo every factor at once, `c` is incremented.
+fn pollard_brent(number: u64) -> u64 {
+    let step = |x: u64, c: u64| {
+        ((u128::from(prime::mul_mod(x, x, number)) + u128::from(c)) % u128::from(number)) as u64
+    };
+    for c in 1.. {
+        let (mut x, mut y, mut saved) = (2, 2, 2);
+        let (mut product, mut divisor, mut length) = (1, 1, 1);
+        while 1 == divisor {
+            x = y;
+            for _ in 0..length {
+                y = step(y, c);
+            }
+            let mut k = 0;
+            while k < length && 1 == divisor {
+                saved = y;
+                for _ in 0..BRENT_BATCH.min(length - k) {
+                    y = step(y, c);
+                    product = prime::mul_mod(product, x.abs_diff(y), number);
+                }
+                divisor = prime::gcd(product, number);
+                k += BRENT_BATCH;
+            }
+            length *= 2;
+        }
+        if number == divisor {
+            // The batched product overshot; retrace the last batch one step at a time.
+            loop {
+                saved = step(saved, c);
+                divisor = prime::gcd(x.abs_diff(saved), number);
+                if 1 != divisor {
+                    break;
+                }
+            }
+        }
+        if number != divisor {
+            return divisor;
+        }
+    }
+    unreachable!();
+}
+
+impl BigUint {
+    /// Factors this number into primes.
+    ///
+    /// Small primes are removed by trial division. Any cofactor that fits in a `u64`
+    /// is handed to `nordint::factorize`; larger cofactors are split with Brent's variant
+    /// of Pollard's rho, so this is only practical when every prime factor beyond the
+    /// second-largest is moderately sized (around 20 digits or fewer).
+    /// Primality of the pieces is decided by `is_probable_prime(0)`.
+    ///
+    /// # Panics
+    /// There is no factorization of zero, so factorizing zero panics.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// // 2 * 101^2 * (2^61 - 1)
+    /// let factorization = BigUint::new("47043809073977783988302").factorize();
+    /// assert_eq!(
+    ///     &[(BigUint::new("2"), 1), (BigUint::new("101"), 2), (BigUint::new("2305843009213693951"), 1)],
+    ///     factorization.factors()
+    /// );
+    /// ```
+    pub fn factorize(&self) -> Factorization<BigUint> {
+        assert!(!self.is_zero(), "cannot factorize zero");
+        let mut primes = Vec::new();
+        let mut number = self.clone();
+        for prime in primes_below(TRIAL_DIVISION_BOUND) {
+            if number.to_u64().is_some() {
+                break;
+            }
+            loop {
+                let (quotient, remainder) = number.div_rem_small(prime as i64);
+                if 0 != remainder {
+                    break;
+                }
+                primes.push(BigUint::from(prime));
+                number = quotient;
+            }
+        }
+
+        let mut composites = vec![number];
+        while let Some(composite) = composites.pop() {
+            if let Some(word) = composite.to_u64() {
+                if 1 < word {
+                    primes.extend(
+                        factorize(word)
+                            .factors()
+                            .iter()
+                            .flat_map(|&(prime, exponent)| (0..exponent).map(move |_| prime))
+                            .map(BigUint::from),
+                    );
+                }
+                continue;
+            }
+            if composite.is_probable_prime(0) {
+                primes.push(composite);
+                continue;
+            }
+            let divisor = composite.pollard_brent();
+            composites.push(div_rem(&composite, &divisor).0);
+            composites.push(divisor);
+        }
+        Factorization::from_primes(primes)
+    }
+
+    /// Brent's variant of Pollard's rho on a BigUint. See `pollard_brent` for the details.
+    fn pollard_brent(&self) -> BigUint {
+        let one = BigUint::one();
+        let abs_diff = |lhs: &BigUint, rhs: &BigUint| {
+            if lhs > rhs {
+                lhs - rhs
+            } else {
+                rhs - lhs
+            }
+        };
+        let mut c = BigUint::one();
+        loop {
+            let step = |x: &BigUint| {
+                let mut next = mul_mod(x, x, self);
+                next += &c;
+                if next >= *self {
+                    next = &next - self;
+                }
+                next
+            };
+            let two = BigUint::from(2_u32);
+            let (mut x, mut y, mut saved) = (two.clone(), two.clone(), two);
+            let (mut product, mut divisor, mut length) = (BigUint::one(), BigUint::one(), 1);
+            while divisor == one {
+                x = y.clone();
+                for _ in 0..length {
+                    y = step(&y);
+                }
+                let mut k = 0;
+                while k < length && divisor == one {
+                    saved = y.clone();
+                    for _ in 0..BRENT_BATCH.min(length - k) {
+                        y = step(&y);
+                        product = mul_mod(&product, &abs_diff(&x, &y), self);
+                    }
+                    divisor = product.gcd(self);
+                    k += BRENT_BATCH;
+                }
+                length *= 2;
+            }
+            if divisor == *self {
+                loop {
+                    saved = step(&saved);
+                    divisor = abs_diff(&x, &saved).gcd(self);
+                    if divisor != one {
+                        break;
+                    }
+                }
+            }
+            if divisor != *self {
+                return divisor;
+            }
+            c += &one;
+        }
+    }
+}
@@ -0,0 +1,181 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+use std::f64::consts::PI;
+use std::ops::{Add, Mul, Sub};
+
+/// A complex number in double precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    fn conj(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+/// Performs an iterative radix-2 FFT in place, where `twiddles[k]` is `e^(-2 pi i k / n)`
+/// for the forward transform, or its conjugate for the inverse.
+fn fft(values: &mut [Complex], twiddles: &[Complex]) {
+    let n = values.len();
+    let bits = n.trailing_zeros();
+    if 0 == bits {
+        return;
+    }
+    for i in 0..n {
+        let reversed = i.reverse_bits() >> (usize::BITS - bits);
+        if i < reversed {
+            values.swap(i, reversed);
+        }
+    }
+
+    let mut half = 1;
+    while half < n {
+        let step = n / (2 * half);
+        for block in values.chunks_mut(2 * half) {
+            let (lower, upper) = block.split_at_mut(half);
+            for (j, (lhs, rhs)) in lower.iter_mut().zip(upper.iter_mut()).enumerate() {
+                let product = *rhs * twiddles[j * step];
+                *rhs = *lhs - product;
+                *lhs = *lhs + product;
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// Euclidean norm of a vector of integers.
+fn norm(values: &[i64]) -> f64 {
+    values.iter().map(|value| (*value as f64).powi(2)).sum::<f64>().sqrt()
+}
+
+/// Percival's bound on the largest error of any coefficient of a linear convolution
+/// computed with a radix-2 double-precision FFT of length `n = 2^k`:
+///
+/// ```text
+///     |error| <= |x| |y| ((1 + e)^(3k) (1 + e sqrt(5))^(3k + 1) (1 + b)^(3k) - 1)
+/// ```
+///
+/// where `|x|` and `|y|` are the Euclidean norms of the inputs, `e = 2^(-53)` is the
+/// unit roundoff and `b` bounds `|w' - w|` for every computed twiddle factor `w'`.
+///
+/// `b` has to hold for the twiddles `twiddles` actually builds, not for exact ones.
+/// Those come from `sin_cos` at angles `t = 2 pi j / n` of at most `pi / 4`, where
+/// the computed angle is off by at most `2e t <= (pi / 2) e`: `PI` and the product
+/// are each rounded once, while the division by a power of two is exact. A change
+/// of the angle moves sine and cosine by no more than the change itself. `sin_cos` is
+/// not correctly rounded, and libm only promises about one ulp, so each component
+/// is allowed two ulps, `2e`, on top. Every twiddle is built from such a pair by exact
+/// swaps and negations, so each of its components is within `(2 + pi / 2) e < 3.6e`,
+/// and `|w' - w| < sqrt(2) * 3.6e < 5.1e`. The bound takes `b = 6e`.
+///
+/// C. Percival, "Rapid multiplication modulo the sum and difference of highly
+/// composite numbers", Mathematics of Computation 72 (2003).
+pub(crate) fn error_bound(lhs: &[i64], rhs: &[i64]) -> f64 {
+    let n = (lhs.len() + rhs.len()).saturating_sub(1).next_power_of_two();
+    let k = f64::from(n.trailing_zeros());
+    let unit = f64::EPSILON / 2.0;
+    let twiddle = 6.0 * unit;
+    let growth = 3.0 * k * unit.ln_1p()
+        + (3.0 * k + 1.0) * (unit * 5_f64.sqrt()).ln_1p()
+        + 3.0 * k * twiddle.ln_1p();
+    norm(lhs) * norm(rhs) * growth.exp_m1()
+}
+
+/// Returns `e^(-2 pi i k / n)` for every `k < n / 2`, where `n` is a power of two.
+///
+/// Only angles in the first octant are passed to `sin_cos`, where both the angle
+/// and its error are smallest. Every other twiddle is the same pair of values
+/// swapped or negated, using `cos(pi/2 - t) = sin(t)` and `cos(pi - t) = -cos(t)`,
+/// which introduces no further rounding.
+fn twiddles(n: usize) -> Vec<Complex> {
+    let octant = |j: usize| (2.0 * PI * j as f64 / n as f64).sin_cos();
+    let quarter = n / 4;
+    (0..n / 2)
+        .map(|k| {
+            // (cos, sin) of the angle 2 pi k / n, which lies in [0, pi).
+            let (cos, sin) = if 8 * k <= n {
+                let (sin, cos) = octant(k);
+                (cos, sin)
+            } else if k <= quarter {
+                octant(quarter - k)
+            } else if 8 * k <= 3 * n {
+                let (sin, cos) = octant(k - quarter);
+                (-sin, cos)
+            } else {
+                let (sin, cos) = octant(2 * quarter - k);
+                (-cos, sin)
+            };
+            Complex::new(cos, -sin)
+        })
+        .collect()
+}
+
+/// Computes the exact linear convolution of two vectors of non-negative integers
+/// with a complex double-precision FFT, rounding each coefficient to the nearest integer.
+///
+/// Returns `None` when `error_bound` cannot guarantee that every coefficient is within
+/// one half of its true value, since rounding could then silently pick the wrong integer.
+pub(crate) fn convolution(lhs: &[i64], rhs: &[i64]) -> Option<Vec<i64>> {
+    if lhs.is_empty() || rhs.is_empty() || error_bound(lhs, rhs) >= 0.5 {
+        return None;
+    }
+    let len = lhs.len() + rhs.len() - 1;
+    let n = len.next_power_of_two();
+    let twiddles = twiddles(n);
+    let transform = |values: &[i64]| {
+        let mut complex = values
+            .iter()
+            .map(|value| Complex::new(*value as f64, 0.0))
+            .collect::<Vec<Complex>>();
+        complex.resize(n, Complex::new(0.0, 0.0));
+        fft(&mut complex, &twiddles);
+        complex
+    };
+
+    let (lhs, rhs) = (transform(lhs), transform(rhs));
+    let mut product = lhs.iter().zip(rhs.iter()).map(|(lx, rx)| *lx * *rx).collect::<Vec<Complex>>();
+    let inverse_twiddles = twiddles.iter().map(|twiddle| twiddle.conj()).collect::<Vec<Complex>>();
+    fft(&mut product, &inverse_twiddles);
+    Some(
+        product
+            .iter()
+            .take(len)
+            .map(|value| (value.re / n as f64).round() as i64)
+            .collect(),
+    )
+}
@@ -0,0 +1,487 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! Binary floating point numbers with an arbitrary number of mantissa bits.
+//!
+//! Every operation first computes enough of the exact result to decide the
+//! rounding, and then rounds it to the nearest value with the result's
+//! precision, breaking ties towards an even mantissa as IEEE 754 does.
+//! The mantissas are multiplied with the same transforms as `BigUint`.
+
+use biguint::div_rem;
+use rational::{scale_by_power_of_two, signed_add};
+use std::cmp::{max, min, Ordering};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use {BigDecimal, BigUint, RoundingMode};
+
+/// A binary floating point number with its own precision.
+///
+/// The value is `mantissa * 2^exponent`, stored as a sign, a mantissa of at
+/// most `precision` bits and an `i64` exponent. Zero is never negative.
+/// A result has the larger precision of its operands, and is the exact
+/// result rounded to nearest, ties to even.
+///
+/// Values compare numerically, whatever their precision.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// let two = BigFloat::from(BigUint::new("2")).with_precision(200);
+/// let root = two.sqrt();
+/// assert_eq!(200, root.precision());
+/// assert!(root.to_string().starts_with("1.41421356237309504880168872420969807856967187537694"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BigFloat {
+    negative: bool,
+    mantissa: BigUint,
+    exponent: i64,
+    precision: u64,
+}
+
+impl BigFloat {
+    /// Creates `mantissa * 2^exponent`, rounded to `precision` bits.
+    ///
+    /// # Panics
+    /// Panics if the precision is zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!("0.75", BigFloat::new(BigUint::new("3"), -2, 10).to_string());
+    /// // 11 is 1011 in binary, a tie between 1010 and 1100 with three bits of precision.
+    /// assert_eq!("12", BigFloat::new(BigUint::new("11"), 0, 3).to_decimal().to_string());
+    /// ```
+    pub fn new(mantissa: BigUint, exponent: i64, precision: u64) -> BigFloat {
+        BigFloat::rounded(false, mantissa, exponent, precision, false)
+    }
+
+    /// Creates zero with the given precision.
+    ///
+    /// # Panics
+    /// Panics if the precision is zero.
+    pub fn zero(precision: u64) -> BigFloat {
+        assert!(0 < precision, "precision must be at least one bit");
+        BigFloat {
+            negative: false,
+            mantissa: BigUint::zero(),
+            exponent: 0,
+            precision,
+        }
+    }
+
+    /// Converts an `f64` exactly, with 53 bits of precision.
+    /// Returns `None` for infinities and NaN.
+    pub fn from_f64(value: f64) -> Option<BigFloat> {
+        if !value.is_finite() {
+            return None;
+        }
+        let bits = value.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if 0 == biased {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+        Some(BigFloat::rounded(1 == bits >> 63, BigUint::from(mantissa), exponent, 53, false))
+    }
+
+    /// Returns the magnitude of the mantissa, which has at most `precision` bits.
+    pub fn mantissa(&self) -> &BigUint {
+        &self.mantissa
+    }
+
+    /// Returns the power of two the mantissa is multiplied by.
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Returns the number of mantissa bits the value is rounded to.
+    pub fn precision(&self) -> u64 {
+        self.precision
+    }
+
+    /// Returns true if the value is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns true if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> BigFloat {
+        BigFloat {
+            negative: false,
+            ..self.clone()
+        }
+    }
+
+    /// Rounds to a new precision. Raising the precision is always exact.
+    ///
+    /// # Panics
+    /// Panics if the precision is zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let third = BigFloat::from(BigUint::one()).with_precision(100) / BigFloat::from(BigUint::new("3"));
+    /// assert_eq!("0.3333", third.with_precision(16).to_string());
+    /// ```
+    pub fn with_precision(&self, precision: u64) -> BigFloat {
+        BigFloat::rounded(self.negative, self.mantissa.clone(), self.exponent, precision, false)
+    }
+
+    /// Calculates the square root, rounded to the same precision.
+    ///
+    /// The mantissa is shifted until it has at least `2 * precision + 2` bits and
+    /// an even exponent, so that the integer square root has a bit to spare below
+    /// the rounding position, and the remainder tells whether anything was cut off.
+    ///
+    /// # Panics
+    /// Panics if the value is negative.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let value = BigFloat::from_f64(2.25).unwrap();
+    /// assert_eq!("1.5", value.sqrt().to_string());
+    /// assert_eq!(2_f64.sqrt(), BigFloat::from_f64(2.0).unwrap().sqrt().to_f64());
+    /// ```
+    pub fn sqrt(&self) -> BigFloat {
+        assert!(!self.negative, "cannot take the square root of a negative BigFloat");
+        if self.is_zero() {
+            return BigFloat::zero(self.precision);
+        }
+        let mut shift = (2 * self.precision + 2).saturating_sub(self.mantissa.bits()) as i64;
+        if 0 != (self.exponent - shift) % 2 {
+            shift += 1;
+        }
+        let (root, remainder) = (&self.mantissa << shift as usize).sqrt_rem();
+        let exponent = (self.exponent - shift) / 2;
+        BigFloat::rounded(false, root, exponent, self.precision, !remainder.is_zero())
+    }
+
+    /// Converts to the nearest `f64`, with ties to even. Values too large for an
+    /// `f64` become infinite.
+    ///
+    /// The value is rounded once, straight to the last bit the `f64` keeps: bit
+    /// `top - 53` of a normal value, but never below `2^-1074`, the smallest
+    /// subnormal, so a subnormal is rounded to exactly the bits it has room for.
+    pub fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let top = self.top();
+        let magnitude = if 1_100 < top {
+            f64::INFINITY
+        } else if top < -1_100 {
+            0.0
+        } else {
+            let last = max(top - 53, -1_074);
+            let mantissa = if self.exponent < last {
+                let unit = BigUint::one() << (last - self.exponent) as usize;
+                let (quotient, remainder) = div_rem(&self.mantissa, &unit);
+                let round_up = match (remainder.trimmed() * 2_u8).cmp(&unit) {
+                    Ordering::Less => false,
+                    Ordering::Equal => !quotient.is_even(),
+                    Ordering::Greater => true,
+                };
+                // At most 2^53, which an f64 still holds exactly.
+                quotient.to_u64().unwrap_or(0) + u64::from(round_up)
+            } else {
+                (&self.mantissa << (self.exponent - last) as usize).to_u64().unwrap_or(0)
+            };
+            scale_by_power_of_two(mantissa as f64, last)
+        };
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Converts to a `BigDecimal` exactly, without trailing zeros. Every power
+    /// of two has a finite decimal expansion, since `2^-k = 5^k * 10^-k`, and the
+    /// decimal digits come straight out of the base-100 buckets of the scaled mantissa.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let tenth = BigFloat::from_f64(0.1).unwrap();
+    /// assert_eq!("0.1000000000000000055511151231257827021181583404541015625", tenth.to_decimal().to_string());
+    /// ```
+    pub fn to_decimal(&self) -> BigDecimal {
+        let magnitude = if 0 <= self.exponent {
+            BigDecimal::from(&self.mantissa << self.exponent as usize)
+        } else {
+            let fives = BigUint::new("5").pow_big(&BigUint::from((-self.exponent) as u64));
+            let fives = fives.expect("the decimal expansion is too long to build");
+            BigDecimal::new(&self.mantissa * &fives, -self.exponent)
+        };
+        let magnitude = magnitude.normalized();
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Writes the value rounded to `digits` significant decimal digits, half to
+    /// even, without trailing zeros after the point.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let value = BigFloat::from_f64(-1234.5678).unwrap();
+    /// assert_eq!("-1234.57", value.to_decimal_string(6));
+    /// assert_eq!("-1200", value.to_decimal_string(2));
+    /// ```
+    pub fn to_decimal_string(&self, digits: usize) -> String {
+        let decimal = self.to_decimal();
+        let excess = decimal.unscaled().num_digits() as i64 - max(digits, 1) as i64;
+        if 0 < excess {
+            decimal.with_scale(decimal.scale() - excess, RoundingMode::HalfEven).normalized().to_string()
+        } else {
+            decimal.to_string()
+        }
+    }
+
+    /// Returns the power of two just above the magnitude, i.e. the exponent of
+    /// the top bit plus one.
+    fn top(&self) -> i64 {
+        self.exponent + self.mantissa.bits() as i64
+    }
+
+    /// Rounds `mantissa * 2^exponent` to `precision` bits, to nearest with ties to even.
+    ///
+    /// A set `sticky` flag means non-zero bits were already cut off below the
+    /// mantissa, which then needs more than `precision` bits so that those bits
+    /// fall below the rounding position and can break a tie upwards.
+    fn rounded(negative: bool, mantissa: BigUint, exponent: i64, precision: u64, sticky: bool) -> BigFloat {
+        assert!(0 < precision, "precision must be at least one bit");
+        let mantissa = mantissa.trimmed();
+        if mantissa.is_zero() {
+            return BigFloat::zero(precision);
+        }
+        let bits = mantissa.bits();
+        if bits <= precision {
+            return BigFloat {
+                negative,
+                mantissa,
+                exponent,
+                precision,
+            };
+        }
+        let shift = bits - precision;
+        let unit = BigUint::one() << shift as usize;
+        let (mut quotient, remainder) = div_rem(&mantissa, &unit);
+        let mut exponent = exponent + shift as i64;
+        let round_up = match (remainder.trimmed() * 2_u8).cmp(&unit) {
+            Ordering::Less => false,
+            Ordering::Equal => sticky || !quotient.is_even(),
+            Ordering::Greater => true,
+        };
+        if round_up {
+            quotient += 1;
+            if precision < quotient.bits() {
+                quotient >>= 1;
+                exponent += 1;
+            }
+        }
+        BigFloat {
+            negative,
+            mantissa: quotient.trimmed(),
+            exponent,
+            precision,
+        }
+    }
+}
+
+impl From<BigUint> for BigFloat {
+    /// Converts exactly, with as many bits of precision as the integer has.
+    fn from(integer: BigUint) -> BigFloat {
+        let precision = max(integer.bits(), 1);
+        BigFloat::new(integer, 0, precision)
+    }
+}
+
+impl From<u64> for BigFloat {
+    /// Converts exactly, with 64 bits of precision.
+    fn from(integer: u64) -> BigFloat {
+        BigFloat::new(BigUint::from(integer), 0, 64)
+    }
+}
+
+impl From<i64> for BigFloat {
+    /// Converts exactly, with 64 bits of precision.
+    fn from(integer: i64) -> BigFloat {
+        BigFloat::rounded(integer < 0, BigUint::from(integer.unsigned_abs()), 0, 64, false)
+    }
+}
+
+impl Ord for BigFloat {
+    /// Compares by sign, then by the position of the top bit, and only then
+    /// by the mantissas shifted to a common exponent.
+    fn cmp(&self, other: &BigFloat) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (negative, _) => {
+                let ordering = match (self.is_zero(), other.is_zero()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) if self.top() != other.top() => self.top().cmp(&other.top()),
+                    (false, false) => {
+                        let exponent = min(self.exponent, other.exponent);
+                        let lhs = &self.mantissa << (self.exponent - exponent) as usize;
+                        lhs.cmp(&(&other.mantissa << (other.exponent - exponent) as usize))
+                    }
+                };
+                if negative {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &BigFloat) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for BigFloat {
+    /// Compares numerically, ignoring the precision.
+    fn eq(&self, other: &BigFloat) -> bool {
+        Ordering::Equal == self.cmp(other)
+    }
+}
+
+impl Eq for BigFloat {}
+
+impl fmt::Display for BigFloat {
+    /// Formats in plain decimal notation with as many significant digits as
+    /// the precision fully determines, i.e. `floor(precision * log10(2))`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = (self.precision as f64 * 2_f64.log10()) as usize;
+        write!(f, "{}", self.to_decimal_string(digits))
+    }
+}
+
+impl Neg for BigFloat {
+    type Output = BigFloat;
+    fn neg(self) -> BigFloat {
+        BigFloat {
+            negative: !self.negative && !self.is_zero(),
+            ..self
+        }
+    }
+}
+
+impl Neg for &BigFloat {
+    type Output = BigFloat;
+    fn neg(self) -> BigFloat {
+        -self.clone()
+    }
+}
+
+impl Add<&BigFloat> for &BigFloat {
+    type Output = BigFloat;
+    fn add(self, rhs: &BigFloat) -> BigFloat {
+        let precision = max(self.precision, rhs.precision);
+        if rhs.is_zero() {
+            return self.with_precision(precision);
+        } else if self.is_zero() {
+            return rhs.with_precision(precision);
+        }
+        let (large, small) = if self.top() < rhs.top() { (rhs, self) } else { (self, rhs) };
+        // Every rounding boundary of the result, and the larger operand itself,
+        // is a multiple of `2^floor`. An operand below that only decides which way
+        // to round, so it is replaced by a single bit rather than aligned bit for bit.
+        let floor = min(large.exponent, large.top() - precision as i64 - 2);
+        let (small_mantissa, small_exponent) = if small.top() <= floor {
+            (BigUint::one(), floor - 1)
+        } else {
+            (small.mantissa.clone(), small.exponent)
+        };
+        let exponent = min(large.exponent, small_exponent);
+        let (negative, mantissa) = signed_add(
+            large.negative,
+            &large.mantissa << (large.exponent - exponent) as usize,
+            small.negative,
+            small_mantissa << (small_exponent - exponent) as usize,
+        );
+        BigFloat::rounded(negative, mantissa, exponent, precision, false)
+    }
+}
+
+impl Sub<&BigFloat> for &BigFloat {
+    type Output = BigFloat;
+    fn sub(self, rhs: &BigFloat) -> BigFloat {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigFloat> for &BigFloat {
+    type Output = BigFloat;
+    fn mul(self, rhs: &BigFloat) -> BigFloat {
+        let precision = max(self.precision, rhs.precision);
+        if self.is_zero() || rhs.is_zero() {
+            return BigFloat::zero(precision);
+        }
+        BigFloat::rounded(
+            self.negative != rhs.negative,
+            &self.mantissa * &rhs.mantissa,
+            self.exponent + rhs.exponent,
+            precision,
+            false,
+        )
+    }
+}
+
+impl Div<&BigFloat> for &BigFloat {
+    type Output = BigFloat;
+    /// The dividend is shifted so that the quotient has at least one bit more
+    /// than the precision, and a non-zero remainder breaks any tie upwards.
+    ///
+    /// Panics if the divisor is zero.
+    fn div(self, rhs: &BigFloat) -> BigFloat {
+        assert!(!rhs.is_zero(), "attempt to divide a BigFloat by zero");
+        let precision = max(self.precision, rhs.precision);
+        if self.is_zero() {
+            return BigFloat::zero(precision);
+        }
+        let shift = (precision + 2 + rhs.mantissa.bits()).saturating_sub(self.mantissa.bits());
+        let (quotient, remainder) = div_rem(&(&self.mantissa << shift as usize), &rhs.mantissa);
+        BigFloat::rounded(
+            self.negative != rhs.negative,
+            quotient,
+            self.exponent - rhs.exponent - shift as i64,
+            precision,
+            !remainder.is_zero(),
+        )
+    }
+}
+
+forward_to_borrowed!(BigFloat, Add, add, AddAssign, add_assign);
+forward_to_borrowed!(BigFloat, Sub, sub, SubAssign, sub_assign);
+forward_to_borrowed!(BigFloat, Mul, mul, MulAssign, mul_assign);
+forward_to_borrowed!(BigFloat, Div, div, DivAssign, div_assign);
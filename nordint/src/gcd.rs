@@ -0,0 +1,253 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+use biguint::{div_rem, linear_combination, rem};
+use std::cmp::{max, Ordering};
+use std::mem::swap;
+use BigUint;
+
+/// Operands of up to this many buckets use the binary algorithm,
+/// and Lehmer's algorithm hands over to it once they are this small.
+const BINARY_GCD_BUCKETS: usize = 16;
+
+/// The number of leading buckets Lehmer's algorithm simulates Euclid's algorithm on.
+/// Seven buckets stay below `10^14`, which keeps every cofactor well inside an i64.
+const LEHMER_BUCKETS: usize = 7;
+
+/// The greatest common divisor of `a` and `b` together with Bézout coefficients
+/// satisfying `a * x + b * y = gcd`. The coefficients are stored as a magnitude
+/// and a sign, since a `BigUint` cannot be negative.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// let bezout = BigUint::new("240").extended_gcd(&BigUint::new("46"));
+/// assert_eq!(&BigUint::new("2"), bezout.gcd());
+/// // 240 * -9 + 46 * 47 = 2
+/// assert_eq!((true, &BigUint::new("9")), bezout.x());
+/// assert_eq!((false, &BigUint::new("47")), bezout.y());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtendedGcd {
+    gcd: BigUint,
+    x: BigUint,
+    x_negative: bool,
+    y: BigUint,
+    y_negative: bool,
+}
+
+impl ExtendedGcd {
+    /// Returns the greatest common divisor.
+    pub fn gcd(&self) -> &BigUint {
+        &self.gcd
+    }
+
+    /// Returns the coefficient of `a` as `(is_negative, magnitude)`.
+    pub fn x(&self) -> (bool, &BigUint) {
+        (self.x_negative, &self.x)
+    }
+
+    /// Returns the coefficient of `b` as `(is_negative, magnitude)`.
+    pub fn y(&self) -> (bool, &BigUint) {
+        (self.y_negative, &self.y)
+    }
+}
+
+impl BigUint {
+    /// Calculates the greatest common divisor.
+    ///
+    /// Small operands use the binary algorithm, which only needs halving and
+    /// subtraction. Larger ones use Lehmer's algorithm, which runs Euclid's algorithm
+    /// on the leading buckets in machine words for as long as the quotients are
+    /// certain to match, and then applies all of those steps to the full numbers at once.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let lhs = BigUint::new("123,456,789,012,345,678,901,234,567,890");
+    /// let rhs = BigUint::new("987,654,321,098,765,432,109,876,543,210");
+    /// assert_eq!(BigUint::new("9,000,000,000,900,000,000,090"), lhs.gcd(&rhs));
+    /// assert_eq!(lhs, lhs.gcd(&BigUint::zero()));
+    /// ```
+    pub fn gcd(&self, other: &BigUint) -> BigUint {
+        let (lhs, rhs) = (self.trimmed(), other.trimmed());
+        if max(lhs.bucket_len(), rhs.bucket_len()) <= BINARY_GCD_BUCKETS {
+            binary_gcd(lhs, rhs)
+        } else {
+            lehmer_gcd(lhs, rhs)
+        }
+    }
+
+    /// Calculates the least common multiple, which is zero if either number is zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(BigUint::new("36"), BigUint::new("12").lcm(&BigUint::new("18")));
+    /// ```
+    pub fn lcm(&self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        &div_rem(self, &self.gcd(other)).0 * other
+    }
+
+    /// Calculates the greatest common divisor along with Bézout coefficients,
+    /// using the extended Euclidean algorithm.
+    ///
+    /// The coefficients of Euclid's algorithm alternate in sign, so only their
+    /// magnitudes are tracked: each new magnitude is the one from two steps back
+    /// plus the quotient times the previous one. The coefficients found are the
+    /// smallest possible: `|x| <= b / (2 gcd)` and `|y| <= a / (2 gcd)`.
+    pub fn extended_gcd(&self, other: &BigUint) -> ExtendedGcd {
+        let (mut lhs, mut rhs) = (self.trimmed(), other.trimmed());
+        let (mut x, mut next_x) = (BigUint::one(), BigUint::zero());
+        let (mut y, mut next_y) = (BigUint::zero(), BigUint::one());
+        let mut steps = 0;
+        while !rhs.is_zero() {
+            let (quotient, remainder) = div_rem(&lhs, &rhs);
+            lhs = rhs;
+            rhs = remainder;
+            let mut x_magnitude = mul_quotient(&next_x, &quotient);
+            x_magnitude += &x;
+            x = next_x;
+            next_x = x_magnitude;
+            let mut y_magnitude = mul_quotient(&next_y, &quotient);
+            y_magnitude += &y;
+            y = next_y;
+            next_y = y_magnitude;
+            steps += 1;
+        }
+        // After an odd number of steps x is negative, and after an even number y is.
+        ExtendedGcd {
+            gcd: lhs,
+            x_negative: 1 == steps % 2 && !x.is_zero(),
+            x,
+            y_negative: 0 == steps % 2 && !y.is_zero(),
+            y,
+        }
+    }
+
+    /// Calculates the inverse of this number modulo `modulus`, if the two are coprime.
+    ///
+    /// # Panics
+    /// Panics if the modulus is zero.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let modulus = BigUint::new("1,000,000,000,000");
+    /// assert_eq!(Some(BigUint::new("857,142,857,143")), BigUint::new("7").mod_inverse(&modulus));
+    /// assert_eq!(None, BigUint::new("6").mod_inverse(&modulus));
+    /// ```
+    pub fn mod_inverse(&self, modulus: &BigUint) -> Option<BigUint> {
+        assert!(!modulus.is_zero(), "modulus must be non-zero");
+        let bezout = rem(self, modulus).extended_gcd(modulus);
+        if BigUint::one() != bezout.gcd {
+            return None;
+        }
+        let modulus = modulus.trimmed();
+        Some(if bezout.x_negative { &modulus - &bezout.x } else { rem(&bezout.x, &modulus) })
+    }
+}
+
+/// Multiplies by a quotient of Euclid's algorithm. Almost every quotient fits in
+/// a u32, which is far cheaper to multiply by than going through the transform.
+fn mul_quotient(number: &BigUint, quotient: &BigUint) -> BigUint {
+    match quotient.to_u64() {
+        Some(small) if small <= u64::from(u32::MAX) => {
+            let mut product = number.clone();
+            product *= small as u32;
+            product
+        }
+        _ => number * quotient,
+    }
+}
+
+/// Divides out every factor of two, returning how many there were.
+fn remove_twos(number: &mut BigUint) -> usize {
+    let mut twos = 0;
+    while !number.is_zero() && number.is_even() {
+        *number = number.div_rem_small(2).0;
+        twos += 1;
+    }
+    twos
+}
+
+/// Stein's binary algorithm: strip the common factors of two, then repeatedly
+/// subtract the smaller odd number from the larger and strip the new factors of two.
+fn binary_gcd(mut lhs: BigUint, mut rhs: BigUint) -> BigUint {
+    if lhs.is_zero() {
+        return rhs;
+    }
+    if rhs.is_zero() {
+        return lhs;
+    }
+    let mut shift = remove_twos(&mut lhs).min(remove_twos(&mut rhs));
+    loop {
+        if Ordering::Greater == lhs.cmp(&rhs) {
+            swap(&mut lhs, &mut rhs);
+        }
+        rhs = &rhs - &lhs;
+        if rhs.is_zero() {
+            break;
+        }
+        remove_twos(&mut rhs);
+    }
+    while 0 < shift {
+        let step = shift.min(16);
+        lhs *= 1_u32 << step;
+        shift -= step;
+    }
+    lhs
+}
+
+/// Lehmer's algorithm, following Knuth's Algorithm L.
+///
+/// Euclid's algorithm is simulated on the leading buckets `x` and `y`, tracking
+/// the cofactors `a, b, c, d` of the full numbers. The quotients of `(x + a) / (y + c)`
+/// and `(x + b) / (y + d)` bracket the true quotient, so while they agree it is safe
+/// to take another step. If not even one step was certain, a full division is made.
+fn lehmer_gcd(mut lhs: BigUint, mut rhs: BigUint) -> BigUint {
+    if Ordering::Less == lhs.cmp(&rhs) {
+        swap(&mut lhs, &mut rhs);
+    }
+    while rhs.bucket_len() > BINARY_GCD_BUCKETS {
+        let start = lhs.bucket_len() - LEHMER_BUCKETS;
+        let (mut x, mut y) = (lhs.buckets_from(start), rhs.buckets_from(start));
+        let (mut a, mut b, mut c, mut d) = (1_i64, 0_i64, 0_i64, 1_i64);
+        while 0 != y + c && 0 != y + d {
+            let quotient = (x + a) / (y + c);
+            if quotient != (x + b) / (y + d) {
+                break;
+            }
+            let next = a - quotient * c;
+            a = c;
+            c = next;
+            let next = b - quotient * d;
+            b = d;
+            d = next;
+            let next = x - quotient * y;
+            x = y;
+            y = next;
+        }
+
+        if 0 == b {
+            let remainder = rem(&lhs, &rhs);
+            lhs = rhs;
+            rhs = remainder;
+        } else {
+            let next_lhs = linear_combination(&lhs, a, &rhs, b);
+            rhs = linear_combination(&lhs, c, &rhs, d);
+            lhs = next_lhs;
+        }
+    }
+    binary_gcd(lhs, rhs)
+}
@@ -1,11 +1,76 @@
 // version: 0.1.0
 // author:  Erik Nordin
 // created: 07/14/2018
-// updated: 07/14/2018
+// updated: 10/18/2026
 // contact: aeketn@gmail.com
 
+/// Implements an operator for the owned combinations of operands, and its
+/// assigning form, on top of the implementation for two borrowed operands.
+macro_rules! forward_to_borrowed {
+    ($t:ty, $imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident) => {
+        impl $imp<$t> for $t {
+            type Output = $t;
+            fn $method(self, rhs: $t) -> $t {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $imp<&$t> for $t {
+            type Output = $t;
+            fn $method(self, rhs: &$t) -> $t {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $imp<$t> for &$t {
+            type Output = $t;
+            fn $method(self, rhs: $t) -> $t {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $imp_assign<&$t> for $t {
+            fn $method_assign(&mut self, rhs: &$t) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+
+        impl $imp_assign<$t> for $t {
+            fn $method_assign(&mut self, rhs: $t) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+    };
+}
+
 mod biguint;
-pub use biguint::BigUint;
+mod bits;
+mod combinatorics;
+mod decimal;
+mod digits;
+mod factor;
+mod fft;
+mod float;
+mod gcd;
+mod modint;
+mod montgomery;
+pub mod ntt;
+mod ops;
+mod pow_mod;
+mod prime;
+mod rational;
+mod roots;
+pub use biguint::{product_tree, BigUint};
+pub use decimal::{BigDecimal, RoundingMode};
+pub use digits::Digits;
+pub use factor::{factorize, Factorization};
+pub use float::BigFloat;
+pub use gcd::ExtendedGcd;
+pub use modint::{DynModInt, ModInt};
+pub use pow_mod::PowMod;
+pub use prime::{is_prime, next_prime, prev_prime};
+pub use rational::BigRational;
+pub use roots::{multiplicative_order, primitive_root, root_of_unity};
 
 use std::error::Error;
 use std::fmt;
@@ -22,6 +87,7 @@ pub struct ParseBigIntError {
 enum BigIntErrorKind {
     Empty,
     InvalidDigit,
+    ZeroDenominator,
 }
 
 impl ParseBigIntError {
@@ -30,6 +96,7 @@ impl ParseBigIntError {
         match self.kind {
             Empty => "cannot parse integer from empty string",
             InvalidDigit => "invalid digit found in string",
+            ZeroDenominator => "denominator is zero",
         }
     }
 
@@ -44,6 +111,12 @@ impl ParseBigIntError {
             kind: BigIntErrorKind::InvalidDigit,
         }
     }
+
+    fn zero_denominator() -> Self {
+        ParseBigIntError {
+            kind: BigIntErrorKind::ZeroDenominator,
+        }
+    }
 }
 
 impl fmt::Display for ParseBigIntError {
@@ -57,3 +130,95 @@ impl Error for ParseBigIntError {
         self.__description()
     }
 }
+
+/// The reasons a primitive root or root of unity can fail to exist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RootOfUnityError {
+    /// The modulus is not prime. Roots are only searched for in prime fields,
+    /// where the multiplicative group is guaranteed to be cyclic.
+    ModulusNotPrime(u64),
+    /// There is no root of unity of order zero.
+    ZeroOrder,
+    /// Every element's order divides `modulus - 1`,
+    /// so no element has an order that does not.
+    OrderDoesNotDivide { order: u64, modulus: u64 },
+}
+
+impl fmt::Display for RootOfUnityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use RootOfUnityError::*;
+        match *self {
+            ModulusNotPrime(modulus) => write!(f, "modulus `{}` is not prime", modulus),
+            ZeroOrder => write!(f, "there is no root of unity of order zero"),
+            OrderDoesNotDivide { order, modulus } => write!(
+                f,
+                "no root of unity of order `{}` exists modulo `{}`: `{}` does not divide `{}`",
+                order,
+                modulus,
+                order,
+                modulus - 1
+            ),
+        }
+    }
+}
+
+impl Error for RootOfUnityError {}
+
+/// The reasons a Number-Theoretic Transform can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NttError {
+    /// A transform was requested on a vector with no elements.
+    EmptyInput,
+    /// A power-of-two kernel was given a length that is not a power of two.
+    LengthNotPowerOfTwo(usize),
+    /// The mixed-radix transform was given a length with a prime factor other than 2, 3 or 5.
+    UnsupportedLength(usize),
+    /// No prime of the form `k * n + 1` exists between the smallest modulus
+    /// that can hold the convolution and the largest modulus an i64 can support,
+    /// or a multi-modular convolution is longer than its primes support.
+    NoModulusFound,
+    /// The modulus has no root of unity of the order the transform needs.
+    NoPrimitiveRoot(RootOfUnityError),
+    /// The transform needs a modulus too large for its products to fit in an i64,
+    /// or a convolution's coefficients might not fit in an i64.
+    ModulusOverflow,
+    /// A plan was asked to transform a different number of elements than it was built for,
+    /// or the two inputs of a cyclic or negacyclic convolution differ in length.
+    LengthMismatch { expected: usize, found: usize },
+}
+
+impl From<RootOfUnityError> for NttError {
+    fn from(error: RootOfUnityError) -> NttError {
+        NttError::NoPrimitiveRoot(error)
+    }
+}
+
+impl fmt::Display for NttError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use NttError::*;
+        match *self {
+            EmptyInput => write!(f, "cannot transform an empty vector"),
+            LengthNotPowerOfTwo(len) => {
+                write!(f, "transform length `{}` is not a power of two", len)
+            }
+            UnsupportedLength(len) => {
+                write!(f, "transform length `{}` has a prime factor other than 2, 3 or 5", len)
+            }
+            NoModulusFound => write!(f, "could not find a working modulus for the provided vectors"),
+            NoPrimitiveRoot(ref error) => write!(f, "no primitive root: {}", error),
+            ModulusOverflow => write!(f, "the required modulus is too large for an i64"),
+            LengthMismatch { expected, found } => {
+                write!(f, "expected `{}` elements but found `{}`", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for NttError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            NttError::NoPrimitiveRoot(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,410 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! Residues that carry their modulus, so every operation reduces for itself.
+//!
+//! `ModInt<M>` fixes the modulus at compile time, for a modulus known up front.
+//! `DynModInt` keeps the modulus next to the value, for a modulus only known at
+//! run time, such as the primes the transforms search for or loop over.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A residue modulo the compile-time constant `M`, always kept in `[0, M)`.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// type Mod7 = ModInt<7>;
+/// let three = Mod7::new(3);
+/// assert_eq!(Mod7::new(2), three * Mod7::new(3));
+/// assert_eq!(Mod7::new(6), Mod7::new(2) - Mod7::new(3));
+/// assert_eq!(Mod7::new(5), Mod7::new(1) / three);
+/// assert_eq!("4", Mod7::new(-3).to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    /// Reduces a signed value into `[0, M)`.
+    ///
+    /// # Panics
+    /// Panics if the modulus is zero.
+    pub fn new(value: i64) -> ModInt<M> {
+        ModInt { value: reduce(value, M) }
+    }
+
+    /// Returns zero.
+    pub fn zero() -> ModInt<M> {
+        ModInt { value: 0 }
+    }
+
+    /// Returns one, which is zero when the modulus is one.
+    pub fn one() -> ModInt<M> {
+        ModInt::from(1_u64)
+    }
+
+    /// Returns the residue, in `[0, M)`.
+    #[inline]
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Returns the modulus `M`.
+    pub fn modulus(self) -> u64 {
+        M
+    }
+
+    /// Raises the residue to a power by repeated squaring.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// // Fermat's little theorem.
+    /// assert_eq!(ModInt::<998_244_353>::one(), ModInt::<998_244_353>::new(3).pow(998_244_352));
+    /// ```
+    pub fn pow(self, exponent: u64) -> ModInt<M> {
+        ModInt {
+            value: pow(self.value, exponent, M),
+        }
+    }
+
+    /// Returns the multiplicative inverse, which exists when the residue is coprime to `M`.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(Some(ModInt::<10>::new(7)), ModInt::<10>::new(3).inv());
+    /// assert_eq!(None, ModInt::<10>::new(4).inv());
+    /// ```
+    pub fn inv(self) -> Option<ModInt<M>> {
+        inverse(self.value, M).map(|value| ModInt { value })
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    /// Reduces an unsigned value into `[0, M)`.
+    fn from(value: u64) -> ModInt<M> {
+        assert!(0 != M, "modulus must be non-zero");
+        ModInt { value: value % M }
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> ModInt<M> {
+        ModInt::new(value)
+    }
+}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    /// Formats the residue alone, without the modulus.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = ModInt<M>;
+    fn neg(self) -> ModInt<M> {
+        ModInt {
+            value: sub(0, self.value, M),
+        }
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = ModInt<M>;
+    #[inline]
+    fn add(self, rhs: ModInt<M>) -> ModInt<M> {
+        ModInt {
+            value: add(self.value, rhs.value, M),
+        }
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = ModInt<M>;
+    #[inline]
+    fn sub(self, rhs: ModInt<M>) -> ModInt<M> {
+        ModInt {
+            value: sub(self.value, rhs.value, M),
+        }
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = ModInt<M>;
+    #[inline]
+    fn mul(self, rhs: ModInt<M>) -> ModInt<M> {
+        ModInt {
+            value: mul(self.value, rhs.value, M),
+        }
+    }
+}
+
+impl<const M: u64> Div for ModInt<M> {
+    type Output = ModInt<M>;
+    /// Multiplies by the inverse of the divisor.
+    ///
+    /// Panics if the divisor has no inverse.
+    fn div(self, rhs: ModInt<M>) -> ModInt<M> {
+        self.mul(rhs.inv().expect("attempt to divide by a residue with no inverse"))
+    }
+}
+
+/// A residue that carries its modulus, always kept in `[0, modulus)`.
+///
+/// Combining residues with different moduli is a bug, and panics in debug builds.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// let modulus = 1_000_000_007;
+/// let half = DynModInt::new(1, modulus) / DynModInt::new(2, modulus);
+/// assert_eq!(500_000_004, half.value());
+/// assert_eq!(DynModInt::new(-1, modulus), -DynModInt::new(1, modulus));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DynModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl DynModInt {
+    /// Reduces a signed value into `[0, modulus)`.
+    ///
+    /// # Panics
+    /// Panics if the modulus is zero.
+    pub fn new(value: i64, modulus: u64) -> DynModInt {
+        DynModInt {
+            value: reduce(value, modulus),
+            modulus,
+        }
+    }
+
+    /// Wraps a value already in `[0, modulus)` without reducing it again.
+    #[inline]
+    pub(crate) fn from_residue(value: u64, modulus: u64) -> DynModInt {
+        debug_assert!(value < modulus, "residue `{}` is not reduced modulo `{}`", value, modulus);
+        DynModInt { value, modulus }
+    }
+
+    /// Returns the residue, in `[0, modulus)`.
+    #[inline]
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    /// Raises the residue to a power by repeated squaring.
+    pub fn pow(self, exponent: u64) -> DynModInt {
+        DynModInt {
+            value: pow(self.value, exponent, self.modulus),
+            ..self
+        }
+    }
+
+    /// Returns the multiplicative inverse, which exists when the residue is coprime to the modulus.
+    pub fn inv(self) -> Option<DynModInt> {
+        inverse(self.value, self.modulus).map(|value| DynModInt { value, ..self })
+    }
+
+    /// Returns `self * rhs + addend`, reducing once rather than after both the
+    /// product and the sum.
+    #[inline]
+    pub(crate) fn mul_add(self, rhs: DynModInt, addend: DynModInt) -> DynModInt {
+        let modulus = self.shared_modulus(rhs);
+        addend.shared_modulus(self);
+        DynModInt::from_residue(mul_add(self.value, rhs.value, addend.value, modulus), modulus)
+    }
+
+    /// Returns the modulus both operands share. The check only runs in debug builds,
+    /// since the transforms combine residues in their innermost loops.
+    #[inline]
+    fn shared_modulus(self, rhs: DynModInt) -> u64 {
+        debug_assert_eq!(self.modulus, rhs.modulus, "residues must share a modulus");
+        self.modulus
+    }
+}
+
+impl fmt::Display for DynModInt {
+    /// Formats the residue alone, without the modulus.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Neg for DynModInt {
+    type Output = DynModInt;
+    fn neg(self) -> DynModInt {
+        DynModInt {
+            value: sub(0, self.value, self.modulus),
+            ..self
+        }
+    }
+}
+
+impl Add for DynModInt {
+    type Output = DynModInt;
+    #[inline]
+    fn add(self, rhs: DynModInt) -> DynModInt {
+        let modulus = self.shared_modulus(rhs);
+        DynModInt::from_residue(add(self.value, rhs.value, modulus), modulus)
+    }
+}
+
+impl Sub for DynModInt {
+    type Output = DynModInt;
+    #[inline]
+    fn sub(self, rhs: DynModInt) -> DynModInt {
+        let modulus = self.shared_modulus(rhs);
+        DynModInt::from_residue(sub(self.value, rhs.value, modulus), modulus)
+    }
+}
+
+impl Mul for DynModInt {
+    type Output = DynModInt;
+    #[inline]
+    fn mul(self, rhs: DynModInt) -> DynModInt {
+        let modulus = self.shared_modulus(rhs);
+        DynModInt::from_residue(mul(self.value, rhs.value, modulus), modulus)
+    }
+}
+
+impl Div for DynModInt {
+    type Output = DynModInt;
+    /// Multiplies by the inverse of the divisor.
+    ///
+    /// Panics if the divisor has no inverse, or, in debug builds, if the moduli differ.
+    fn div(self, rhs: DynModInt) -> DynModInt {
+        self.shared_modulus(rhs);
+        self.mul(rhs.inv().expect("attempt to divide by a residue with no inverse"))
+    }
+}
+
+/// Implements the assigning operators of a `Copy` residue on top of the plain ones.
+macro_rules! forward_assign {
+    ([$($generics:tt)*] $t:ty) => {
+        impl<$($generics)*> AddAssign for $t {
+            #[inline]
+            fn add_assign(&mut self, rhs: $t) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<$($generics)*> SubAssign for $t {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $t) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<$($generics)*> MulAssign for $t {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<$($generics)*> DivAssign for $t {
+            fn div_assign(&mut self, rhs: $t) {
+                *self = *self / rhs;
+            }
+        }
+    };
+}
+
+forward_assign!([const M: u64] ModInt<M>);
+forward_assign!([] DynModInt);
+
+/// Reduces a signed value into `[0, modulus)`.
+fn reduce(value: i64, modulus: u64) -> u64 {
+    assert!(0 != modulus, "modulus must be non-zero");
+    i128::from(value).rem_euclid(i128::from(modulus)) as u64
+}
+
+/// Adds two residues. Neither is above `modulus - 1`, so one subtraction reduces the sum.
+#[inline]
+fn add(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    let (sum, overflowed) = lhs.overflowing_add(rhs);
+    if overflowed || modulus <= sum {
+        sum.wrapping_sub(modulus)
+    } else {
+        sum
+    }
+}
+
+/// Subtracts two residues, adding the modulus back when the difference would be negative.
+#[inline]
+fn sub(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    if rhs <= lhs {
+        lhs - rhs
+    } else {
+        lhs.wrapping_sub(rhs).wrapping_add(modulus)
+    }
+}
+
+/// Multiplies two residues.
+#[inline]
+fn mul(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    mul_add(lhs, rhs, 0, modulus)
+}
+
+/// Calculates `lhs * rhs + addend` with a single reduction. For moduli up to `2^32`,
+/// which every transform prime is, that is at most `modulus^2 - modulus` and fits
+/// in a u64, so only larger moduli widen to u128.
+#[inline]
+fn mul_add(lhs: u64, rhs: u64, addend: u64, modulus: u64) -> u64 {
+    if modulus <= 1 << 32 {
+        (lhs * rhs + addend) % modulus
+    } else {
+        ((u128::from(lhs) * u128::from(rhs) + u128::from(addend)) % u128::from(modulus)) as u64
+    }
+}
+
+/// Calculates `base^exponent` by repeated squaring.
+fn pow(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    while 0 < exponent {
+        if 1 == exponent & 1 {
+            result = mul(result, base, modulus);
+        }
+        base = mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Finds the inverse with the extended Euclidean algorithm, so the modulus
+/// does not need to be prime. Returns `None` unless the gcd is one.
+fn inverse(value: u64, modulus: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (i128::from(value), i128::from(modulus));
+    let (mut old_s, mut s) = (1_i128, 0_i128);
+    while 0 != r {
+        let quotient = old_r / r;
+        let next_r = old_r - quotient * r;
+        old_r = r;
+        r = next_r;
+        let next_s = old_s - quotient * s;
+        old_s = s;
+        s = next_s;
+    }
+    if 1 == old_r {
+        Some(old_s.rem_euclid(i128::from(modulus)) as u64)
+    } else {
+        None
+    }
+}
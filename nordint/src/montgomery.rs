@@ -0,0 +1,269 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+use std::arch::x86_64::*;
+
+/// Arithmetic modulo an odd `p < 2^31` on residues kept in Montgomery form,
+/// where `a` is stored as `a * 2^32 mod p`.
+///
+/// A product of two such residues is brought back into range by Montgomery
+/// reduction, which only needs multiplications and a shift rather than a `%`.
+/// That makes it cheap to vectorize: with the `simd` feature, `butterflies`
+/// and `pointwise` run eight residues at a time with AVX2 whenever the CPU
+/// supports it, and fall back to the scalar kernels otherwise.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt::Montgomery;
+/// let montgomery = Montgomery::new(998_244_353).unwrap();
+/// let lhs = montgomery.to_montgomery(123_456_789);
+/// let rhs = montgomery.to_montgomery(987_654_321);
+/// let product = montgomery.from_montgomery(montgomery.mul(lhs, rhs));
+/// assert_eq!((123_456_789_u64 * 987_654_321 % 998_244_353) as u32, product);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Montgomery {
+    modulus: u32,
+    /// `-p^(-1) mod 2^32`
+    inverse: u32,
+    /// `2^64 mod p`, which moves a residue into Montgomery form in one reduction.
+    r_squared: u32,
+}
+
+impl Montgomery {
+    /// Prepares Montgomery arithmetic for an odd modulus below `2^31`.
+    /// Returns `None` for any other modulus.
+    pub fn new(modulus: u32) -> Option<Montgomery> {
+        if 0 == modulus & 1 || modulus >= 1 << 31 {
+            return None;
+        }
+        // Newton's iteration for p^(-1) mod 2^32: p is its own inverse mod 8,
+        // and every step doubles the number of correct bits.
+        let mut inverse = modulus;
+        for _ in 0..4 {
+            inverse = inverse.wrapping_mul(2_u32.wrapping_sub(modulus.wrapping_mul(inverse)));
+        }
+        let r_squared = ((1_u128 << 64) % u128::from(modulus)) as u32;
+        Some(Montgomery {
+            modulus,
+            inverse: inverse.wrapping_neg(),
+            r_squared,
+        })
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> u32 {
+        self.modulus
+    }
+
+    /// Montgomery reduction: returns `value * 2^(-32) mod p` for any `value < p * 2^32`.
+    #[inline]
+    fn reduce(&self, value: u64) -> u32 {
+        let m = (value as u32).wrapping_mul(self.inverse);
+        let reduced = ((value + u64::from(m) * u64::from(self.modulus)) >> 32) as u32;
+        if reduced >= self.modulus {
+            reduced - self.modulus
+        } else {
+            reduced
+        }
+    }
+
+    /// Moves a value into Montgomery form.
+    pub fn to_montgomery(&self, value: u32) -> u32 {
+        self.reduce(u64::from(value % self.modulus) * u64::from(self.r_squared))
+    }
+
+    /// Moves a residue out of Montgomery form.
+    pub fn from_montgomery(&self, residue: u32) -> u32 {
+        self.reduce(u64::from(residue))
+    }
+
+    /// Multiplies two residues in Montgomery form.
+    #[inline]
+    pub fn mul(&self, lhs: u32, rhs: u32) -> u32 {
+        self.reduce(u64::from(lhs) * u64::from(rhs))
+    }
+
+    #[inline]
+    fn add(&self, lhs: u32, rhs: u32) -> u32 {
+        let sum = lhs + rhs;
+        if sum >= self.modulus {
+            sum - self.modulus
+        } else {
+            sum
+        }
+    }
+
+    #[inline]
+    fn sub(&self, lhs: u32, rhs: u32) -> u32 {
+        if lhs >= rhs {
+            lhs - rhs
+        } else {
+            lhs + self.modulus - rhs
+        }
+    }
+
+    /// Multiplies `lhs` by `rhs` element by element, in place.
+    ///
+    /// # Panics
+    /// Panics if the slices differ in length.
+    pub fn pointwise(&self, lhs: &mut [u32], rhs: &[u32]) {
+        assert_eq!(lhs.len(), rhs.len(), "pointwise operands differ in length");
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // Safe: the CPU was just checked for AVX2.
+                return unsafe { self.pointwise_avx2(lhs, rhs) };
+            }
+        }
+        self.pointwise_scalar(lhs, rhs);
+    }
+
+    /// The scalar kernel behind `pointwise`.
+    pub fn pointwise_scalar(&self, lhs: &mut [u32], rhs: &[u32]) {
+        assert_eq!(lhs.len(), rhs.len(), "pointwise operands differ in length");
+        for (lx, rx) in lhs.iter_mut().zip(rhs.iter()) {
+            *lx = self.mul(*lx, *rx);
+        }
+    }
+
+    /// Performs the radix-2 butterflies of one level of a transform, in place:
+    /// `(lower[i], upper[i]) = (lower[i] + w[i] * upper[i], lower[i] - w[i] * upper[i])`.
+    ///
+    /// # Panics
+    /// Panics if the slices differ in length.
+    pub fn butterflies(&self, lower: &mut [u32], upper: &mut [u32], twiddles: &[u32]) {
+        assert!(
+            lower.len() == upper.len() && lower.len() == twiddles.len(),
+            "butterfly operands differ in length"
+        );
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // Safe: the CPU was just checked for AVX2.
+                return unsafe { self.butterflies_avx2(lower, upper, twiddles) };
+            }
+        }
+        self.butterflies_scalar(lower, upper, twiddles);
+    }
+
+    /// The scalar kernel behind `butterflies`.
+    pub fn butterflies_scalar(&self, lower: &mut [u32], upper: &mut [u32], twiddles: &[u32]) {
+        assert!(
+            lower.len() == upper.len() && lower.len() == twiddles.len(),
+            "butterfly operands differ in length"
+        );
+        for ((lhs, rhs), twiddle) in lower.iter_mut().zip(upper.iter_mut()).zip(twiddles.iter()) {
+            let product = self.mul(*rhs, *twiddle);
+            *rhs = self.sub(*lhs, product);
+            *lhs = self.add(*lhs, product);
+        }
+    }
+
+    /// Performs an iterative radix-2 transform of residues in `[0, p)`, where `powers`
+    /// holds every power of the root of unity. The values are moved into Montgomery
+    /// form, transformed level by level with `butterflies`, and moved back out.
+    #[cfg(feature = "simd")]
+    pub(crate) fn cooley_tukey(&self, elements: &mut [i64], powers: &[i64]) {
+        let n = elements.len();
+        let bits = n.trailing_zeros();
+        let mut residues = vec![0; n];
+        for (i, element) in elements.iter().enumerate() {
+            // Bit reversal puts the inputs where the in-place levels expect them.
+            let reversed = if 0 == bits { 0 } else { i.reverse_bits() >> (usize::BITS - bits) };
+            residues[reversed] = self.to_montgomery(*element as u32);
+        }
+
+        let mut half = 1;
+        while half < n {
+            let step = n / (2 * half);
+            let twiddles = (0..half)
+                .map(|j| self.to_montgomery(powers[j * step] as u32))
+                .collect::<Vec<u32>>();
+            for block in residues.chunks_mut(2 * half) {
+                let (lower, upper) = block.split_at_mut(half);
+                self.butterflies(lower, upper, &twiddles);
+            }
+            half *= 2;
+        }
+
+        for (element, residue) in elements.iter_mut().zip(residues) {
+            *element = i64::from(self.from_montgomery(residue));
+        }
+    }
+
+    /// Montgomery multiplication of eight residues at once. `_mm256_mul_epu32` only
+    /// multiplies the even 32-bit lanes, so the odd lanes are shifted down and
+    /// multiplied separately, and the two halves are blended back together.
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_avx2(&self, lhs: __m256i, rhs: __m256i) -> __m256i {
+        let modulus = _mm256_set1_epi32(self.modulus as i32);
+        let even = self.reduce_avx2(_mm256_mul_epu32(lhs, rhs), modulus);
+        let odd = self.reduce_avx2(
+            _mm256_mul_epu32(_mm256_srli_epi64(lhs, 32), _mm256_srli_epi64(rhs, 32)),
+            modulus,
+        );
+        // The reduced values sit in the high half of each 64-bit lane.
+        let reduced = _mm256_blend_epi32(_mm256_srli_epi64(even, 32), odd, 0b1010_1010);
+        self.normalize_avx2(reduced, modulus)
+    }
+
+    /// Montgomery reduction of four 64-bit products, leaving each result,
+    /// still below `2p`, in the high half of its lane.
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn reduce_avx2(&self, products: __m256i, modulus: __m256i) -> __m256i {
+        let m = _mm256_mul_epu32(products, _mm256_set1_epi32(self.inverse as i32));
+        _mm256_add_epi64(products, _mm256_mul_epu32(m, modulus))
+    }
+
+    /// Maps values in `[0, 2p)` into `[0, p)`: when `x < p`, `x - p` wraps around
+    /// to something larger than `x`, so the unsigned minimum picks the right one.
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn normalize_avx2(&self, values: __m256i, modulus: __m256i) -> __m256i {
+        _mm256_min_epu32(values, _mm256_sub_epi32(values, modulus))
+    }
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn pointwise_avx2(&self, lhs: &mut [u32], rhs: &[u32]) {
+        let vectors = lhs.len() / 8;
+        for i in 0..vectors {
+            let lhs_ptr = lhs.as_mut_ptr().add(8 * i) as *mut __m256i;
+            let rhs_ptr = rhs.as_ptr().add(8 * i) as *const __m256i;
+            let product = self.mul_avx2(_mm256_loadu_si256(lhs_ptr), _mm256_loadu_si256(rhs_ptr));
+            _mm256_storeu_si256(lhs_ptr, product);
+        }
+        self.pointwise_scalar(&mut lhs[8 * vectors..], &rhs[8 * vectors..]);
+    }
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn butterflies_avx2(&self, lower: &mut [u32], upper: &mut [u32], twiddles: &[u32]) {
+        let modulus = _mm256_set1_epi32(self.modulus as i32);
+        let vectors = lower.len() / 8;
+        for i in 0..vectors {
+            let lower_ptr = lower.as_mut_ptr().add(8 * i) as *mut __m256i;
+            let upper_ptr = upper.as_mut_ptr().add(8 * i) as *mut __m256i;
+            let twiddle_ptr = twiddles.as_ptr().add(8 * i) as *const __m256i;
+            let lhs = _mm256_loadu_si256(lower_ptr);
+            let product = self.mul_avx2(_mm256_loadu_si256(upper_ptr), _mm256_loadu_si256(twiddle_ptr));
+            // Both operands are below p < 2^31, so the sum cannot wrap,
+            // and a wrapped difference is fixed by adding p back.
+            let sum = self.normalize_avx2(_mm256_add_epi32(lhs, product), modulus);
+            let difference = _mm256_sub_epi32(lhs, product);
+            let difference = _mm256_min_epu32(difference, _mm256_add_epi32(difference, modulus));
+            _mm256_storeu_si256(lower_ptr, sum);
+            _mm256_storeu_si256(upper_ptr, difference);
+        }
+        let tail = 8 * vectors;
+        self.butterflies_scalar(&mut lower[tail..], &mut upper[tail..], &twiddles[tail..]);
+    }
+}
@@ -0,0 +1,982 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! Number-Theoretic Transforms and the convolutions built on top of them.
+//!
+//! Every transform works on residues in `[0, modulus)` and expects `omega`
+//! to be a primitive `n`th root of unity under the modulus, where `n` is the
+//! number of elements. `nordint::root_of_unity` will find one.
+
+pub use montgomery::Montgomery;
+use prime;
+use roots::root_of_unity;
+use std::cmp::{max, min};
+#[cfg(feature = "parallel")]
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+#[cfg(feature = "parallel")]
+use std::thread;
+use {DynModInt, NttError};
+
+/// The largest modulus the transform can use: products of two residues
+/// must fit in an i64, so the modulus must not exceed sqrt(std::i64::MAX).
+pub const MAX_MODULUS: i64 = 3_037_000_499;
+
+/// The radices supported by the mixed-radix transform.
+const RADICES: [usize; 3] = [2, 3, 5];
+
+/// Primes below `MAX_MODULUS` of the form `k * 2^23 + 1`, so each has a root of unity
+/// of every power-of-two order up to `2^23`. Their product exceeds `i64::MAX`, so a
+/// multi-modular convolution can recover any coefficient that fits in an i64.
+const CRT_PRIMES: [i64; 4] = [998_244_353, 754_974_721, 469_762_049, 167_772_161];
+
+/// With the `parallel` feature, a transform of at least this many elements
+/// hands its subtransforms to separate threads.
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// Wraps an element that is already in `[0, modulus)`, so that the butterflies
+/// cannot forget to reduce their sums, differences and products.
+#[inline]
+fn residue(value: i64, modulus: i64) -> DynModInt {
+    DynModInt::from_residue(value as u64, modulus as u64)
+}
+
+/// Calculates the modular inverse of `value` under a prime modulus.
+/// By Fermat's little theorem, `value^(p - 1) = 1`, so `value^(p - 2)` is the inverse.
+fn mod_inverse(value: i64, modulus: i64) -> i64 {
+    DynModInt::new(value, modulus as u64).pow(modulus as u64 - 2).value() as i64
+}
+
+/// Returns `[omega^0, omega^1, ..., omega^(n - 1)]`.
+/// Each kernel looks its twiddle factors up in this table rather than
+/// calling `pow_mod` once per butterfly.
+fn powers_of(omega: i64, n: usize, modulus: i64) -> Vec<i64> {
+    let omega = DynModInt::new(omega, modulus as u64);
+    let mut powers = Vec::with_capacity(n);
+    let mut power = DynModInt::new(1, modulus as u64);
+    for _ in 0..n {
+        powers.push(power.value() as i64);
+        power *= omega;
+    }
+    powers
+}
+
+/// Checks the conditions that every transform shares.
+fn validate(len: usize, modulus: i64) -> Result<(), NttError> {
+    if 0 == len {
+        return Err(NttError::EmptyInput);
+    }
+    if modulus > MAX_MODULUS {
+        return Err(NttError::ModulusOverflow);
+    }
+    Ok(())
+}
+
+/// Returns true if the length only has 2, 3 and 5 as prime factors.
+fn is_smooth(mut n: usize) -> bool {
+    if 0 == n {
+        return false;
+    }
+    for radix in RADICES.iter() {
+        while n.is_multiple_of(*radix) {
+            n /= radix;
+        }
+    }
+    1 == n
+}
+
+/// Returns the smallest length at least `n` that the mixed-radix transform supports,
+/// i.e. the smallest number of the form `2^a * 3^b * 5^c` that is `>= n`.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// assert_eq!(1, ntt::smooth_length(0));
+/// assert_eq!(16, ntt::smooth_length(16));
+/// assert_eq!(18, ntt::smooth_length(17));
+/// assert_eq!(125, ntt::smooth_length(121));
+/// ```
+pub fn smooth_length(n: usize) -> usize {
+    (max(n, 1)..).find(|length| is_smooth(*length)).unwrap()
+}
+
+/// Runs `task` on every item and collects the results in order.
+///
+/// With the `parallel` feature, and once `len` reaches `PARALLEL_THRESHOLD`,
+/// the first item runs on the calling thread and every other item gets its own
+/// scoped thread, as long as that keeps the threads spawned across every
+/// transform within the number of cores. Otherwise, such as deep in the recursion
+/// of a transform that is already running on every core, the items run serially.
+/// Every task writes only to its own item, so the results are identical to the serial path.
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+fn for_each_task<T, R, F>(items: &mut [T], len: usize, task: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(usize, &mut T) -> R + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        if len >= PARALLEL_THRESHOLD && 1 < items.len() {
+            if let Some(_reservation) = ThreadReservation::new(items.len() - 1) {
+                let task = &task;
+                return thread::scope(|scope| {
+                    let (first, rest) = items.split_first_mut().unwrap();
+                    let handles = rest
+                        .iter_mut()
+                        .enumerate()
+                        .map(|(index, item)| scope.spawn(move || task(index + 1, item)))
+                        .collect::<Vec<_>>();
+                    let mut results = vec![task(0, first)];
+                    results.extend(
+                        handles
+                            .into_iter()
+                            .map(|handle| handle.join().expect("transform thread panicked")),
+                    );
+                    results
+                });
+            }
+        }
+    }
+    items.iter_mut().enumerate().map(|(index, item)| task(index, item)).collect()
+}
+
+/// The number of threads `for_each_task` has spawned that are still running.
+#[cfg(feature = "parallel")]
+static SPAWNED_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// A claim on some of the threads `for_each_task` may spawn, released when dropped,
+/// even if a task panics.
+#[cfg(feature = "parallel")]
+struct ThreadReservation(usize);
+
+#[cfg(feature = "parallel")]
+impl ThreadReservation {
+    /// Claims `count` threads, or returns `None` if that would leave more
+    /// threads running than the machine has cores.
+    fn new(count: usize) -> Option<ThreadReservation> {
+        let cores = thread::available_parallelism().map_or(1, |cores| cores.get());
+        SPAWNED_THREADS
+            .fetch_update(AtomicOrdering::SeqCst, AtomicOrdering::SeqCst, |spawned| {
+                Some(spawned + count).filter(|total| *total < cores)
+            })
+            .ok()
+            .map(|_| ThreadReservation(count))
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl Drop for ThreadReservation {
+    fn drop(&mut self) {
+        SPAWNED_THREADS.fetch_sub(self.0, AtomicOrdering::SeqCst);
+    }
+}
+
+/// Collects every other element of a slice of [i64] given a starting index
+/// ```text
+///     Example: [1, 5, 3, 5, 2, 6, 9] where start_index == 0
+///     Returns: [1, 3, 2, 9]
+///
+///     Example: [1, 5, 3, 5, 2, 6, 9, 4] where start_index == 1
+///     Returns: [5, 5, 6, 4]
+/// ```
+fn every_other_element_starting_at(start_index: usize, elements: &[i64]) -> Vec<i64> {
+    (start_index..elements.len())
+        .step_by(2)
+        .map(|i| elements[i])
+        .collect()
+}
+
+/// The algorithm a `Plan` runs its transforms with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kernel {
+    /// Radix-2 Cooley-Tukey. Power-of-two lengths only.
+    ///
+    /// With the `simd` feature, an odd modulus below `2^31` runs this kernel
+    /// over the vectorized Montgomery butterflies, on a single thread.
+    Radix2,
+    /// Radix-4 Cooley-Tukey, finishing with a radix-2 pass when the length
+    /// is an odd power of two. Power-of-two lengths only.
+    Radix4,
+    /// Split-radix: one half-length and two quarter-length subtransforms per level.
+    /// Power-of-two lengths only.
+    ///
+    /// With the `simd` feature, an odd modulus below `2^31` runs the vectorized
+    /// radix-2 Montgomery butterflies instead, as `Radix2` does, since eight
+    /// butterflies at a time beat the scalar split radix's fewer multiplications.
+    SplitRadix,
+    /// Mixed-radix Cooley-Tukey. Lengths of the form `2^a * 3^b * 5^c` only.
+    MixedRadix,
+    /// Bluestein's chirp-z transform. Any length `n`, provided the modulus
+    /// also has a power-of-two root of unity of order at least `2n - 1`.
+    Bluestein,
+}
+
+impl Kernel {
+    /// Returns the kernel `forward`, `inverse` and `Plan::new` use for a length:
+    /// split radix for powers of two, mixed radix for other lengths of the form
+    /// `2^a * 3^b * 5^c`, and Bluestein for everything else.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::ntt::Kernel;
+    /// assert_eq!(Kernel::SplitRadix, Kernel::for_length(1024));
+    /// assert_eq!(Kernel::MixedRadix, Kernel::for_length(1000));
+    /// assert_eq!(Kernel::Bluestein, Kernel::for_length(1001));
+    /// ```
+    pub fn for_length(len: usize) -> Kernel {
+        if len.is_power_of_two() {
+            Kernel::SplitRadix
+        } else if is_smooth(len) {
+            Kernel::MixedRadix
+        } else {
+            Kernel::Bluestein
+        }
+    }
+
+    /// Checks that this kernel can transform `len` elements under the modulus.
+    fn check(self, len: usize, modulus: i64) -> Result<(), NttError> {
+        match self {
+            Kernel::Radix2 | Kernel::Radix4 | Kernel::SplitRadix if !len.is_power_of_two() => {
+                Err(NttError::LengthNotPowerOfTwo(len))
+            }
+            Kernel::MixedRadix if !is_smooth(len) => Err(NttError::UnsupportedLength(len)),
+            Kernel::Bluestein => {
+                root_of_unity((2 * len - 1).next_power_of_two() as u64, modulus as u64)?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Runs the kernel, where `powers` holds every power of the root of unity.
+    fn run(self, elements: &mut [i64], powers: &[i64], modulus: i64) -> Result<(), NttError> {
+        match self {
+            Kernel::Radix2 | Kernel::SplitRadix if montgomery_transform(elements, powers, modulus) => {}
+            Kernel::Radix2 => cooley_tukey_recursive(elements, powers, 1, modulus),
+            Kernel::Radix4 => radix_4_recursive(elements, powers, 1, modulus),
+            Kernel::SplitRadix => split_radix_recursive(elements, powers, 1, modulus),
+            Kernel::MixedRadix => mixed_radix_recursive(elements, powers, 1, modulus),
+            Kernel::Bluestein => return bluestein_with(elements, powers, modulus),
+        }
+        Ok(())
+    }
+}
+
+/// A transform of a fixed length under a fixed root of unity and modulus.
+///
+/// The plan validates the length against its kernel and builds the tables of
+/// twiddle factors once, so they can be shared between every transform of
+/// that length, such as the two forward and one inverse transform of a convolution.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt::{Kernel, Plan};
+/// let plan = Plan::new(8, 998_244_353).unwrap().with_kernel(Kernel::Radix4).unwrap();
+/// let original = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// let mut elements = original.clone();
+/// plan.forward(&mut elements).unwrap();
+/// assert_eq!(31, elements[0]);
+/// plan.inverse(&mut elements).unwrap();
+/// assert_eq!(original, elements);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Plan {
+    kernel: Kernel,
+    omega: i64,
+    modulus: i64,
+    powers: Vec<i64>,
+    inverse_powers: Vec<i64>,
+    len_inverse: i64,
+}
+
+impl Plan {
+    /// Creates a plan for `len` elements under a prime modulus, finding
+    /// the root of unity with `nordint::root_of_unity` and the kernel with `Kernel::for_length`.
+    pub fn new(len: usize, modulus: i64) -> Result<Plan, NttError> {
+        validate(len, modulus)?;
+        let omega = root_of_unity(len as u64, modulus as u64)? as i64;
+        Plan::with_root(len, omega, modulus)
+    }
+
+    /// Creates a plan for `len` elements with the given primitive `len`th root of unity.
+    pub fn with_root(len: usize, omega: i64, modulus: i64) -> Result<Plan, NttError> {
+        validate(len, modulus)?;
+        let kernel = Kernel::for_length(len);
+        kernel.check(len, modulus)?;
+        let powers = powers_of(omega, len, modulus);
+        // omega^(-i) = omega^(n - i), so the inverse table is the forward table reversed.
+        let inverse_powers = (0..len).map(|i| powers[(len - i) % len]).collect();
+        Ok(Plan {
+            kernel,
+            omega,
+            modulus,
+            powers,
+            inverse_powers,
+            len_inverse: mod_inverse(len as i64 % modulus, modulus),
+        })
+    }
+
+    /// Replaces the kernel, failing if it cannot transform this plan's length.
+    pub fn with_kernel(mut self, kernel: Kernel) -> Result<Plan, NttError> {
+        kernel.check(self.size(), self.modulus)?;
+        self.kernel = kernel;
+        Ok(self)
+    }
+
+    /// Returns the number of elements this plan transforms.
+    pub fn size(&self) -> usize {
+        self.powers.len()
+    }
+
+    /// Returns the kernel this plan runs.
+    pub fn kernel(&self) -> Kernel {
+        self.kernel
+    }
+
+    /// Returns the root of unity of the forward transform.
+    pub fn omega(&self) -> i64 {
+        self.omega
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> i64 {
+        self.modulus
+    }
+
+    /// Performs the forward transform in place.
+    pub fn forward(&self, elements: &mut [i64]) -> Result<(), NttError> {
+        self.check_len(elements)?;
+        self.kernel.run(elements, &self.powers, self.modulus)
+    }
+
+    /// Performs the inverse transform in place: the forward transform
+    /// under `omega^(-1)`, with each element scaled by the inverse of n.
+    pub fn inverse(&self, elements: &mut [i64]) -> Result<(), NttError> {
+        self.check_len(elements)?;
+        self.kernel.run(elements, &self.inverse_powers, self.modulus)?;
+        let len_inverse = residue(self.len_inverse, self.modulus);
+        for element in elements.iter_mut() {
+            *element = (residue(*element, self.modulus) * len_inverse).value() as i64;
+        }
+        Ok(())
+    }
+
+    fn check_len(&self, elements: &[i64]) -> Result<(), NttError> {
+        if elements.len() != self.size() {
+            return Err(NttError::LengthMismatch {
+                expected: self.size(),
+                found: elements.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Runs a single kernel directly on a slice, under the given root of unity.
+fn transform(kernel: Kernel, elements: &mut [i64], omega: i64, modulus: i64) -> Result<(), NttError> {
+    validate(elements.len(), modulus)?;
+    kernel.check(elements.len(), modulus)?;
+    let powers = powers_of(omega, elements.len(), modulus);
+    kernel.run(elements, &powers, modulus)
+}
+
+/// Performs the Cooley-Tukey O(n log(n)) algorithm on a slice of [i64].
+/// The algorithm is performed using a number-theoretic transform,
+/// where omega is the first of nth roots of unity under the provided modulus.
+/// n must be a power of two.
+///
+/// https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // 4 is a primitive 4th root of unity mod 17
+/// let mut elements = vec![1, 2, 3, 4];
+/// ntt::cooley_tukey(&mut elements, 4, 17).unwrap();
+/// assert_eq!(vec![10, 7, 15, 6], elements);
+/// ```
+pub fn cooley_tukey(elements: &mut [i64], omega: i64, modulus: i64) -> Result<(), NttError> {
+    transform(Kernel::Radix2, elements, omega, modulus)
+}
+
+/// With the `simd` feature, returns the Montgomery arithmetic the power-of-two
+/// transforms and `pointwise` run over, for an odd modulus below `2^31`.
+#[cfg(feature = "simd")]
+fn montgomery_for(modulus: i64) -> Option<Montgomery> {
+    if 0 < modulus && modulus < 1 << 31 {
+        Montgomery::new(modulus as u32)
+    } else {
+        None
+    }
+}
+
+/// With the `simd` feature, runs a power-of-two transform over the vectorized
+/// Montgomery butterflies when the modulus allows it. Returns false, leaving
+/// the elements untouched, when the transform has to run on the scalar kernels.
+#[cfg_attr(not(feature = "simd"), allow(unused_variables))]
+fn montgomery_transform(elements: &mut [i64], powers: &[i64], modulus: i64) -> bool {
+    #[cfg(feature = "simd")]
+    {
+        if let Some(montgomery) = montgomery_for(modulus) {
+            montgomery.cooley_tukey(elements, powers);
+            return true;
+        }
+    }
+    false
+}
+
+/// Recursively performs the radix-2 butterflies.
+/// `powers[i * stride]` is the ith power of this level's root of unity.
+fn cooley_tukey_recursive(elements: &mut [i64], powers: &[i64], stride: usize, modulus: i64) {
+    let len = elements.len();
+    if len == 1 {
+        return;
+    }
+
+    let mut halves = vec![
+        every_other_element_starting_at(0, elements),
+        every_other_element_starting_at(1, elements),
+    ];
+    for_each_task(&mut halves, len, |_, half| {
+        cooley_tukey_recursive(half, powers, stride * 2, modulus)
+    });
+    let (even_index_elements, odd_index_elements) = (&halves[0], &halves[1]);
+
+    for i in 0..len / 2 {
+        let lhs = residue(even_index_elements[i], modulus);
+        let rhs = residue(powers[i * stride], modulus) * residue(odd_index_elements[i], modulus);
+        elements[i] = (lhs + rhs).value() as i64;
+        elements[i + len / 2] = (lhs - rhs).value() as i64;
+    }
+}
+
+/// Performs a radix-4 Cooley-Tukey transform on a power-of-two length.
+///
+/// Each level splits the elements into four interleaved subsequences and recombines
+/// them with 4-point butterflies. Multiplying by `omega^(n/4)`, the square root of -1,
+/// replaces half of the twiddle multiplications a radix-2 level pair would need.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // 4 is a primitive 4th root of unity mod 17
+/// let mut elements = vec![1, 2, 3, 4];
+/// ntt::radix_4(&mut elements, 4, 17).unwrap();
+/// assert_eq!(vec![10, 7, 15, 6], elements);
+/// ```
+pub fn radix_4(elements: &mut [i64], omega: i64, modulus: i64) -> Result<(), NttError> {
+    transform(Kernel::Radix4, elements, omega, modulus)
+}
+
+/// Recursively performs the radix-4 butterflies, with a radix-2 butterfly
+/// at the bottom of odd powers of two.
+/// `powers[i * stride]` is the ith power of this level's root of unity.
+fn radix_4_recursive(elements: &mut [i64], powers: &[i64], stride: usize, modulus: i64) {
+    let len = elements.len();
+    if len < 4 {
+        return cooley_tukey_recursive(elements, powers, stride, modulus);
+    }
+    let quarter = len / 4;
+
+    let mut subsequences = (0..4)
+        .map(|start| elements.iter().skip(start).step_by(4).cloned().collect())
+        .collect::<Vec<Vec<i64>>>();
+    for_each_task(&mut subsequences, len, |_, subsequence| {
+        radix_4_recursive(subsequence, powers, stride * 4, modulus)
+    });
+
+    let twiddle = |power: usize| residue(powers[power], modulus);
+    let imaginary = twiddle(quarter * stride);
+    for k in 0..quarter {
+        let a0 = residue(subsequences[0][k], modulus);
+        let a1 = twiddle(k * stride) * residue(subsequences[1][k], modulus);
+        let a2 = twiddle(2 * k * stride) * residue(subsequences[2][k], modulus);
+        let a3 = twiddle(3 * k * stride) * residue(subsequences[3][k], modulus);
+        let (sum_02, diff_02) = (a0 + a2, a0 - a2);
+        let (sum_13, diff_13) = (a1 + a3, (a1 - a3) * imaginary);
+        elements[k] = (sum_02 + sum_13).value() as i64;
+        elements[k + quarter] = (diff_02 + diff_13).value() as i64;
+        elements[k + 2 * quarter] = (sum_02 - sum_13).value() as i64;
+        elements[k + 3 * quarter] = (diff_02 - diff_13).value() as i64;
+    }
+}
+
+/// Performs a split-radix transform on a power-of-two length.
+///
+/// The even-indexed elements are transformed at half length, as in radix 2,
+/// but the odd-indexed elements are split again into those at `4j + 1` and `4j + 3`
+/// and transformed at quarter length. This needs the fewest twiddle multiplications
+/// of the power-of-two kernels, and is the one `forward` uses.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // 4 is a primitive 4th root of unity mod 17
+/// let mut elements = vec![1, 2, 3, 4];
+/// ntt::split_radix(&mut elements, 4, 17).unwrap();
+/// assert_eq!(vec![10, 7, 15, 6], elements);
+/// ```
+pub fn split_radix(elements: &mut [i64], omega: i64, modulus: i64) -> Result<(), NttError> {
+    transform(Kernel::SplitRadix, elements, omega, modulus)
+}
+
+/// Recursively performs the split-radix butterflies.
+/// `powers[i * stride]` is the ith power of this level's root of unity.
+fn split_radix_recursive(elements: &mut [i64], powers: &[i64], stride: usize, modulus: i64) {
+    let len = elements.len();
+    if len < 4 {
+        return cooley_tukey_recursive(elements, powers, stride, modulus);
+    }
+    let quarter = len / 4;
+
+    // The evens at half length, then the elements at 4j + 1 and 4j + 3 at quarter length.
+    let mut subsequences = vec![
+        every_other_element_starting_at(0, elements),
+        elements.iter().skip(1).step_by(4).cloned().collect(),
+        elements.iter().skip(3).step_by(4).cloned().collect(),
+    ];
+    for_each_task(&mut subsequences, len, |index, subsequence| {
+        let step = if 0 == index { 2 } else { 4 };
+        split_radix_recursive(subsequence, powers, stride * step, modulus)
+    });
+    let (evens, ones, threes) = (&subsequences[0], &subsequences[1], &subsequences[2]);
+
+    let twiddle = |power: usize| residue(powers[power], modulus);
+    let imaginary = twiddle(quarter * stride);
+    for k in 0..quarter {
+        let z1 = twiddle(k * stride) * residue(ones[k], modulus);
+        let z3 = twiddle(3 * k * stride) * residue(threes[k], modulus);
+        let (sum, diff) = (z1 + z3, (z1 - z3) * imaginary);
+        let (lower, upper) = (residue(evens[k], modulus), residue(evens[k + quarter], modulus));
+        elements[k] = (lower + sum).value() as i64;
+        elements[k + quarter] = (upper + diff).value() as i64;
+        elements[k + 2 * quarter] = (lower - sum).value() as i64;
+        elements[k + 3 * quarter] = (upper - diff).value() as i64;
+    }
+}
+
+/// Performs a mixed-radix Cooley-Tukey transform on any length of the form `2^a * 3^b * 5^c`.
+///
+/// At each level the elements are split into `r` interleaved subsequences, where `r`
+/// is the smallest of 2, 3 and 5 that divides the length. Each subsequence is transformed
+/// recursively, and the results are recombined with an `r`-point DFT per output.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // 8 is a primitive 6th root of unity mod 19
+/// let mut elements = vec![1, 2, 3, 4, 5, 6];
+/// ntt::mixed_radix(&mut elements, 8, 19).unwrap();
+/// assert_eq!(vec![2, 9, 1, 16, 12, 4], elements);
+/// ```
+pub fn mixed_radix(elements: &mut [i64], omega: i64, modulus: i64) -> Result<(), NttError> {
+    transform(Kernel::MixedRadix, elements, omega, modulus)
+}
+
+/// Recursively performs the mixed-radix butterflies.
+/// `powers[i * stride]` is the ith power of this level's root of unity.
+fn mixed_radix_recursive(elements: &mut [i64], powers: &[i64], stride: usize, modulus: i64) {
+    let len = elements.len();
+    if len == 1 {
+        return;
+    }
+    let radix = *RADICES.iter().find(|radix| len.is_multiple_of(**radix)).unwrap();
+    let sub_len = len / radix;
+
+    let mut subsequences = (0..radix)
+        .map(|start| elements.iter().skip(start).step_by(radix).cloned().collect())
+        .collect::<Vec<Vec<i64>>>();
+    for_each_task(&mut subsequences, len, |_, subsequence| {
+        mixed_radix_recursive(subsequence, powers, stride * radix, modulus)
+    });
+
+    for k in 0..sub_len {
+        for t in 0..radix {
+            let index = k + sub_len * t;
+            let zero = residue(0, modulus);
+            let sum = subsequences.iter().enumerate().fold(zero, |sum, (s, subsequence)| {
+                let twiddle = residue(powers[s * index * stride % powers.len()], modulus);
+                twiddle.mul_add(residue(subsequence[k], modulus), sum)
+            });
+            elements[index] = sum.value() as i64;
+        }
+    }
+}
+
+/// Performs Bluestein's (chirp-z) transform on any length `n`.
+///
+/// Using the identity `jk = T(j + k) - T(j) - T(k)`, where `T(m) = m(m - 1) / 2`,
+/// the transform is rewritten as a correlation of `x_j * omega^(-T(j))` with the chirp
+/// `omega^T(m)`. That correlation is computed with a power-of-two convolution
+/// of length at least `2n - 1`, so the modulus must also have a root of unity of that order.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // 7 divides 998244352, and so does 16 >= 2 * 7 - 1
+/// let omega = nordint::root_of_unity(7, 998_244_353).unwrap() as i64;
+/// let mut elements = vec![1, 2, 3, 4, 5, 6, 7];
+/// ntt::bluestein(&mut elements, omega, 998_244_353).unwrap();
+/// assert_eq!(28, elements[0]);
+/// ```
+pub fn bluestein(elements: &mut [i64], omega: i64, modulus: i64) -> Result<(), NttError> {
+    transform(Kernel::Bluestein, elements, omega, modulus)
+}
+
+/// Performs Bluestein's transform, where `powers` holds every power of the root of unity.
+fn bluestein_with(elements: &mut [i64], powers: &[i64], modulus: i64) -> Result<(), NttError> {
+    let n = elements.len();
+    let plan = Plan::new((2 * n - 1).next_power_of_two(), modulus)?;
+
+    // Exponents only matter mod n, since omega^n = 1, and omega^(-T) = omega^(n - T).
+    let triangle = |m: usize| (m * m.saturating_sub(1) / 2) % n;
+    let inverse_chirp = |m: usize| residue(powers[(n - triangle(m)) % n], modulus);
+
+    // Reversing the weighted input turns the correlation into a convolution.
+    let mut weighted = vec![0; plan.size()];
+    for (j, element) in elements.iter().enumerate() {
+        weighted[n - 1 - j] = (residue(*element, modulus) * inverse_chirp(j)).value() as i64;
+    }
+    let mut chirp = vec![0; plan.size()];
+    for (m, value) in chirp.iter_mut().enumerate().take(2 * n - 1) {
+        *value = powers[triangle(m)];
+    }
+
+    plan.forward(&mut weighted)?;
+    plan.forward(&mut chirp)?;
+    let mut correlation = pointwise(&weighted, &chirp, modulus);
+    plan.inverse(&mut correlation)?;
+
+    for (k, element) in elements.iter_mut().enumerate() {
+        *element = (residue(correlation[n - 1 + k], modulus) * inverse_chirp(k)).value() as i64;
+    }
+    Ok(())
+}
+
+/// Performs the forward transform with the kernel `Kernel::for_length` chooses.
+/// To pick the kernel, or to reuse the twiddle factors across transforms, use a `Plan`.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // 998244352 = 2^23 * 7 * 17
+/// let modulus = 998_244_353;
+/// for &n in &[1, 2, 7, 8, 14, 17, 28] {
+///     let omega = nordint::root_of_unity(n as u64, modulus as u64).unwrap() as i64;
+///     let original = (1..=n).collect::<Vec<i64>>();
+///     let mut elements = original.clone();
+///     ntt::forward(&mut elements, omega, modulus).unwrap();
+///     ntt::inverse(&mut elements, omega, modulus).unwrap();
+///     assert_eq!(original, elements);
+/// }
+/// ```
+pub fn forward(elements: &mut [i64], omega: i64, modulus: i64) -> Result<(), NttError> {
+    Plan::with_root(elements.len(), omega, modulus)?.forward(elements)
+}
+
+/// Performs the inverse transform, undoing `forward` with the same omega and modulus.
+///
+/// The inverse is the forward transform under `omega^(-1)`, with each element
+/// scaled by the modular inverse of n.
+pub fn inverse(elements: &mut [i64], omega: i64, modulus: i64) -> Result<(), NttError> {
+    Plan::with_root(elements.len(), omega, modulus)?.inverse(elements)
+}
+
+/// Finds a modulus M such that:
+///   M is a prime number.
+///   M - 1 is divisible by the transform length n
+///   M is larger than any element of the convolution could be
+fn find_convolution_modulus(n: i64, lhs: &[i64], rhs: &[i64]) -> Result<i64, NttError> {
+    let max_elem = max(
+        *lhs.iter().max().ok_or(NttError::EmptyInput)?,
+        *rhs.iter().max().ok_or(NttError::EmptyInput)?,
+    );
+    let minimum_modulus = max_elem
+        .checked_pow(2)
+        .and_then(|square| square.checked_mul(n))
+        .and_then(|bound| bound.checked_add(1))
+        .filter(|minimum_modulus| *minimum_modulus <= MAX_MODULUS)
+        .ok_or(NttError::ModulusOverflow)?;
+    let start = (minimum_modulus - 1) / n;
+
+    (start..)
+        .map(|k| k * n + 1)
+        .take_while(|modulus| *modulus <= MAX_MODULUS)
+        .find(|modulus| *modulus > minimum_modulus && prime::is_prime(*modulus as u64))
+        .ok_or(NttError::NoModulusFound)
+}
+
+/// Computes the exact linear convolution of two vectors of non-negative integers.
+///
+/// Both vectors are zero-padded to the smallest length of the form `2^a * 3^b * 5^c`
+/// that holds the full convolution, which is never more than 25% padding, rather than
+/// to the next power of two. A prime modulus large enough that no element of the
+/// convolution wraps around is found, the vectors are transformed, multiplied
+/// point-wise and transformed back.
+///
+/// When no single prime below `MAX_MODULUS` is large enough, the convolution
+/// falls back to `multi_modular_convolution`.
+///
+/// Any trailing zeros of the convolution are removed.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // (1 + 2x + 3x^2)(4 + 5x) = 4 + 13x + 22x^2 + 15x^3
+/// assert_eq!(Ok(vec![4, 13, 22, 15]), ntt::fft_convolution(&[1, 2, 3], &[4, 5]));
+/// ```
+pub fn fft_convolution(lhs: &[i64], rhs: &[i64]) -> Result<Vec<i64>, NttError> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Err(NttError::EmptyInput);
+    }
+    let n = smooth_length(lhs.len() + rhs.len() - 1);
+    let modulus = match find_convolution_modulus(n as i64, lhs, rhs) {
+        Err(NttError::ModulusOverflow) => return multi_modular_convolution(lhs, rhs),
+        modulus => modulus?,
+    };
+    let plan = Plan::new(n, modulus)?;
+
+    let mut lhs = lhs.to_vec();
+    let mut rhs = rhs.to_vec();
+    lhs.resize(n, 0);
+    rhs.resize(n, 0);
+    plan.forward(&mut lhs)?;
+    plan.forward(&mut rhs)?;
+
+    let mut convolution = pointwise(&lhs, &rhs, modulus);
+    plan.inverse(&mut convolution)?;
+
+    while convolution.ends_with(&[0]) {
+        convolution.pop();
+    }
+    Ok(convolution)
+}
+
+/// Computes the exact linear convolution of two vectors of non-negative integers
+/// by convolving under several primes and recombining with the Chinese Remainder Theorem.
+///
+/// Each prime in `CRT_PRIMES` supports power-of-two lengths up to `2^23`, and just
+/// enough of them are used for their product to exceed the largest possible coefficient.
+/// The convolutions under each prime are independent, so with the `parallel`
+/// feature they run on separate threads.
+///
+/// Any trailing zeros of the convolution are removed.
+///
+/// # Errors
+/// `NoModulusFound` if the convolution needs more than `2^23` elements, and
+/// `ModulusOverflow` if a coefficient of the convolution might not fit in an i64.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // Coefficients up to 3 * 10^18 need a modulus far beyond any single prime.
+/// let big = 1_000_000_000;
+/// assert_eq!(
+///     Ok(vec![big * big, 3 * big * big, 2 * big * big]),
+///     ntt::multi_modular_convolution(&[big, big], &[big, 2 * big])
+/// );
+/// ```
+pub fn multi_modular_convolution(lhs: &[i64], rhs: &[i64]) -> Result<Vec<i64>, NttError> {
+    let max_lhs = *lhs.iter().max().ok_or(NttError::EmptyInput)?;
+    let max_rhs = *rhs.iter().max().ok_or(NttError::EmptyInput)?;
+    let n = (lhs.len() + rhs.len() - 1).next_power_of_two();
+    if n > 1 << 23 {
+        return Err(NttError::NoModulusFound);
+    }
+    let bound = max_lhs as u128 * max_rhs as u128 * min(lhs.len(), rhs.len()) as u128;
+    if bound > i64::MAX as u128 {
+        return Err(NttError::ModulusOverflow);
+    }
+    let mut product = 1_u128;
+    let count = 1 + CRT_PRIMES
+        .iter()
+        .take_while(|prime| {
+            product *= **prime as u128;
+            product <= bound
+        })
+        .count();
+    let primes = &CRT_PRIMES[..count];
+
+    let mut residues = primes.iter().map(|prime| (*prime, Vec::new())).collect::<Vec<_>>();
+    let results = for_each_task(&mut residues, n, |_, (prime, convolution)| {
+        let prime = *prime;
+        let plan = Plan::new(n, prime)?;
+        let reduce = |elements: &[i64]| {
+            let mut reduced = elements.iter().map(|element| element % prime).collect::<Vec<i64>>();
+            reduced.resize(n, 0);
+            reduced
+        };
+        let (mut lhs, mut rhs) = (reduce(lhs), reduce(rhs));
+        plan.forward(&mut lhs)?;
+        plan.forward(&mut rhs)?;
+        *convolution = pointwise(&lhs, &rhs, prime);
+        plan.inverse(convolution)
+    });
+    results.into_iter().collect::<Result<Vec<()>, NttError>>()?;
+
+    // Garner's algorithm: the coefficient is c_0 + c_1 * p_0 + c_2 * p_0 * p_1 + ...
+    // where each c_i is found modulo p_i from the ones before it. Every coefficient
+    // shares the prefix products, so `prefixes[i][j]` holds p_0 * ... * p_(j - 1) mod p_i.
+    let prefixes = primes
+        .iter()
+        .enumerate()
+        .map(|(i, prime)| {
+            let one = DynModInt::new(1, *prime as u64);
+            primes[..=i]
+                .iter()
+                .scan(one, |prefix, p| {
+                    let current = *prefix;
+                    *prefix *= DynModInt::new(*p, *prime as u64);
+                    Some(current)
+                })
+                .collect::<Vec<DynModInt>>()
+        })
+        .collect::<Vec<Vec<DynModInt>>>();
+    let prefix_inverses = prefixes
+        .iter()
+        .map(|prefix| prefix.last().unwrap().inv().expect("the primes are distinct"))
+        .collect::<Vec<DynModInt>>();
+    let mut convolution = (0..n)
+        .map(|k| {
+            let mut digits: Vec<i64> = Vec::with_capacity(count);
+            for (i, prime) in primes.iter().enumerate() {
+                // Each earlier digit is below its own prime, which may exceed this one.
+                let partial = digits.iter().zip(prefixes[i].iter()).fold(residue(0, *prime), |partial, (digit, prefix)| {
+                    partial + residue(digit % prime, *prime) * *prefix
+                });
+                let digit = (residue(residues[i].1[k], *prime) - partial) * prefix_inverses[i];
+                digits.push(digit.value() as i64);
+            }
+            let (mut value, mut prefix) = (0_u128, 1_u128);
+            for (digit, prime) in digits.iter().zip(primes.iter()) {
+                value += *digit as u128 * prefix;
+                prefix *= *prime as u128;
+            }
+            value as i64
+        })
+        .collect::<Vec<i64>>();
+
+    while convolution.ends_with(&[0]) {
+        convolution.pop();
+    }
+    Ok(convolution)
+}
+
+/// Multiplies two transformed vectors element by element.
+///
+/// With the `simd` feature, and a modulus `Montgomery` supports, only `lhs` is moved
+/// into Montgomery form: the Montgomery product of `a * 2^32` and `b` is `a * b`,
+/// so the vectorized products come out already in the ordinary form.
+fn pointwise(lhs: &[i64], rhs: &[i64], modulus: i64) -> Vec<i64> {
+    #[cfg(feature = "simd")]
+    {
+        if let Some(montgomery) = montgomery_for(modulus) {
+            let mut product = lhs
+                .iter()
+                .map(|element| montgomery.to_montgomery(*element as u32))
+                .collect::<Vec<u32>>();
+            let rhs = rhs.iter().map(|element| *element as u32).collect::<Vec<u32>>();
+            montgomery.pointwise(&mut product, &rhs);
+            return product.into_iter().map(i64::from).collect();
+        }
+    }
+    lhs.iter()
+        .zip(rhs.iter())
+        .map(|(lx, rx)| (residue(*lx, modulus) * residue(*rx, modulus)).value() as i64)
+        .collect()
+}
+
+/// Reduces the inputs of a modular convolution into `[0, modulus)`,
+/// checking that both have the same length.
+fn residues(lhs: &[i64], rhs: &[i64], modulus: i64) -> Result<(Vec<i64>, Vec<i64>), NttError> {
+    validate(lhs.len(), modulus)?;
+    if lhs.len() != rhs.len() {
+        return Err(NttError::LengthMismatch {
+            expected: lhs.len(),
+            found: rhs.len(),
+        });
+    }
+    let reduce = |elements: &[i64]| elements.iter().map(|element| element.rem_euclid(modulus)).collect();
+    Ok((reduce(lhs), reduce(rhs)))
+}
+
+/// Computes the cyclic convolution of two vectors of length `n` under a prime modulus:
+/// `c_k = sum of a_i * b_j over every i + j = k (mod n)`, which is the product
+/// in the ring `Z_p[x] / (x^n - 1)`.
+///
+/// Unlike `fft_convolution` nothing is padded, so `n` itself must divide `p - 1`.
+/// Negative inputs are reduced into `[0, modulus)` first.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // (1 + 2x + 3x^2)(4 + 5x) = 4 + 13x + 22x^2 + 15x^3, and x^3 = 1
+/// assert_eq!(Ok(vec![19, 13, 22]), ntt::cyclic_convolution(&[1, 2, 3], &[4, 5, 0], 97));
+/// ```
+pub fn cyclic_convolution(lhs: &[i64], rhs: &[i64], modulus: i64) -> Result<Vec<i64>, NttError> {
+    let (mut lhs, mut rhs) = residues(lhs, rhs, modulus)?;
+    let plan = Plan::new(lhs.len(), modulus)?;
+    plan.forward(&mut lhs)?;
+    plan.forward(&mut rhs)?;
+    let mut convolution = pointwise(&lhs, &rhs, modulus);
+    plan.inverse(&mut convolution)?;
+    Ok(convolution)
+}
+
+/// Computes the negacyclic convolution of two vectors of length `n` under a prime modulus:
+/// the product in the ring `Z_p[x] / (x^n + 1)`, where terms that wrap past `x^(n - 1)`
+/// come back negated.
+///
+/// With `psi` a primitive `2n`th root of unity, `x^n + 1` becomes `x^n - 1` after
+/// substituting `x -> psi * x`. So each input is twisted by `psi^j`, convolved
+/// cyclically under `omega = psi^2`, and the result untwisted by `psi^(-j)`.
+/// `2n` must divide `p - 1`. Negative inputs are reduced into `[0, modulus)` first.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::ntt;
+/// // (1 + 2x + 3x^2)(4 + 5x) = 4 + 13x + 22x^2 + 15x^3, and x^3 = -1
+/// assert_eq!(Ok(vec![86, 13, 22]), ntt::negacyclic_convolution(&[1, 2, 3], &[4, 5, 0], 97));
+/// ```
+pub fn negacyclic_convolution(lhs: &[i64], rhs: &[i64], modulus: i64) -> Result<Vec<i64>, NttError> {
+    let (mut lhs, mut rhs) = residues(lhs, rhs, modulus)?;
+    let n = lhs.len();
+    let psi = root_of_unity(2 * n as u64, modulus as u64)? as i64;
+    let twists = powers_of(psi, 2 * n, modulus);
+    let omega = residue(psi, modulus) * residue(psi, modulus);
+    let plan = Plan::with_root(n, omega.value() as i64, modulus)?;
+
+    for (j, (lx, rx)) in lhs.iter_mut().zip(rhs.iter_mut()).enumerate() {
+        let twist = residue(twists[j], modulus);
+        *lx = (residue(*lx, modulus) * twist).value() as i64;
+        *rx = (residue(*rx, modulus) * twist).value() as i64;
+    }
+    plan.forward(&mut lhs)?;
+    plan.forward(&mut rhs)?;
+    let mut convolution = pointwise(&lhs, &rhs, modulus);
+    plan.inverse(&mut convolution)?;
+
+    // psi^(-j) = psi^(2n - j)
+    for (j, element) in convolution.iter_mut().enumerate() {
+        let untwist = residue(twists[(2 * n - j) % (2 * n)], modulus);
+        *element = (residue(*element, modulus) * untwist).value() as i64;
+    }
+    Ok(convolution)
+}
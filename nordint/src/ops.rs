@@ -0,0 +1,385 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! The arithmetic operators for every combination of owned and borrowed
+//! operands, and for mixing a `BigUint` with the unsigned primitives.
+//! The binary operators take every unsigned width on either side, and an i32 as
+//! well, so that an unsuffixed literal, which falls back to i32, works as in
+//! `number * 3`. The assigning operators only take a u32, so `number *= 2` and
+//! `number += 1` resolve to it.
+//!
+//! Everything here is built on the in-place `+=`, `-=` and `*= u32` and on the
+//! borrowed forms of the other operators, such as `&a * &b` and `&a & &b`.
+//! Wherever an operand is owned its buckets are reused for the result,
+//! so `a + b` allocates nothing unless the sum grows a bucket.
+
+use biguint::div_rem;
+use std::convert::TryFrom;
+use std::mem::swap;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use std::ops::{Shl, ShlAssign, Shr, ShrAssign};
+use BigUint;
+
+impl AddAssign<BigUint> for BigUint {
+    /// Adds into whichever operand has more buckets, so the buffer that is
+    /// kept is the one least likely to need to grow.
+    fn add_assign(&mut self, mut rhs: BigUint) {
+        if !self.is_empty() && self.bucket_len() < rhs.bucket_len() {
+            swap(self, &mut rhs);
+        }
+        *self += &rhs;
+    }
+}
+
+impl Add<BigUint> for BigUint {
+    type Output = BigUint;
+    fn add(mut self, rhs: BigUint) -> BigUint {
+        self += rhs;
+        self
+    }
+}
+
+impl Add<&BigUint> for BigUint {
+    type Output = BigUint;
+    fn add(mut self, rhs: &BigUint) -> BigUint {
+        self += rhs;
+        self
+    }
+}
+
+impl Add<BigUint> for &BigUint {
+    type Output = BigUint;
+    /// Adds into the owned operand, unless the borrowed one is empty,
+    /// since the sum of an empty left-hand side is empty.
+    fn add(self, mut rhs: BigUint) -> BigUint {
+        if self.is_empty() {
+            return BigUint::empty();
+        }
+        rhs += self;
+        rhs
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn add(self, rhs: &BigUint) -> BigUint {
+        self.clone() + rhs
+    }
+}
+
+impl SubAssign<BigUint> for BigUint {
+    fn sub_assign(&mut self, rhs: BigUint) {
+        *self -= &rhs;
+    }
+}
+
+impl Sub<BigUint> for BigUint {
+    type Output = BigUint;
+    fn sub(mut self, rhs: BigUint) -> BigUint {
+        self -= &rhs;
+        self
+    }
+}
+
+impl Sub<&BigUint> for BigUint {
+    type Output = BigUint;
+    fn sub(mut self, rhs: &BigUint) -> BigUint {
+        self -= rhs;
+        self
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+    /// Panics if the divisor is zero.
+    fn div(self, rhs: &BigUint) -> BigUint {
+        div_rem(self, rhs).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+    /// Panics if the divisor is zero.
+    fn rem(self, rhs: &BigUint) -> BigUint {
+        div_rem(self, rhs).1
+    }
+}
+
+forward_to_borrowed!(BigUint, Mul, mul, MulAssign, mul_assign);
+forward_to_borrowed!(BigUint, Div, div, DivAssign, div_assign);
+forward_to_borrowed!(BigUint, Rem, rem, RemAssign, rem_assign);
+forward_to_borrowed!(BigUint, BitAnd, bitand, BitAndAssign, bitand_assign);
+forward_to_borrowed!(BigUint, BitOr, bitor, BitOrAssign, bitor_assign);
+forward_to_borrowed!(BigUint, BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+/// Implements a shift of an owned number, and its assigning form,
+/// on top of the shift of a borrowed one.
+macro_rules! forward_shift {
+    ($imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident) => {
+        impl $imp<usize> for BigUint {
+            type Output = BigUint;
+            fn $method(self, rhs: usize) -> BigUint {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $imp_assign<usize> for BigUint {
+            fn $method_assign(&mut self, rhs: usize) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+    };
+}
+
+forward_shift!(Shl, shl, ShlAssign, shl_assign);
+forward_shift!(Shr, shr, ShrAssign, shr_assign);
+
+/// Divides by a primitive. Divisors that fit in a u32 take a single pass of
+/// short division instead of the full long division.
+fn div_rem_primitive(lhs: &BigUint, rhs: u128) -> (BigUint, BigUint) {
+    assert!(0 != rhs, "attempt to divide a BigUint by zero");
+    if rhs <= u128::from(u32::MAX) {
+        let (quotient, remainder) = lhs.div_rem_small(rhs as i64);
+        (quotient, BigUint::from(remainder as u64))
+    } else {
+        div_rem(lhs, &BigUint::from(rhs))
+    }
+}
+
+/// Multiplies by a primitive. Factors that fit in a u32 are multiplied into each
+/// bucket in place; larger ones go through the full multiplication.
+fn mul_primitive(lhs: &mut BigUint, rhs: u128) {
+    if rhs <= u128::from(u32::MAX) {
+        *lhs *= rhs as u32;
+    } else {
+        *lhs = &*lhs * &BigUint::from(rhs);
+    }
+}
+
+/// Implements every operator between a `BigUint` and a primitive, with the
+/// primitive on either side. The result is always a `BigUint`.
+macro_rules! primitive_ops {
+    ($($primitive:ty),*) => {$(
+        impl Add<$primitive> for BigUint {
+            type Output = BigUint;
+            fn add(mut self, rhs: $primitive) -> BigUint {
+                self += &BigUint::from(rhs);
+                self
+            }
+        }
+
+        impl Add<$primitive> for &BigUint {
+            type Output = BigUint;
+            fn add(self, rhs: $primitive) -> BigUint {
+                self.clone() + rhs
+            }
+        }
+
+        impl Add<BigUint> for $primitive {
+            type Output = BigUint;
+            fn add(self, rhs: BigUint) -> BigUint {
+                BigUint::from(self) + rhs
+            }
+        }
+
+        impl Add<&BigUint> for $primitive {
+            type Output = BigUint;
+            fn add(self, rhs: &BigUint) -> BigUint {
+                BigUint::from(self) + rhs
+            }
+        }
+
+        impl Sub<$primitive> for BigUint {
+            type Output = BigUint;
+            fn sub(mut self, rhs: $primitive) -> BigUint {
+                self -= &BigUint::from(rhs);
+                self
+            }
+        }
+
+        impl Sub<$primitive> for &BigUint {
+            type Output = BigUint;
+            fn sub(self, rhs: $primitive) -> BigUint {
+                self.clone() - rhs
+            }
+        }
+
+        impl Sub<BigUint> for $primitive {
+            type Output = BigUint;
+            fn sub(self, rhs: BigUint) -> BigUint {
+                &BigUint::from(self) - rhs
+            }
+        }
+
+        impl Sub<&BigUint> for $primitive {
+            type Output = BigUint;
+            fn sub(self, rhs: &BigUint) -> BigUint {
+                BigUint::from(self) - rhs
+            }
+        }
+
+        impl Mul<$primitive> for BigUint {
+            type Output = BigUint;
+            fn mul(mut self, rhs: $primitive) -> BigUint {
+                mul_primitive(&mut self, rhs as u128);
+                self
+            }
+        }
+
+        impl Mul<$primitive> for &BigUint {
+            type Output = BigUint;
+            fn mul(self, rhs: $primitive) -> BigUint {
+                self.clone() * rhs
+            }
+        }
+
+        impl Mul<BigUint> for $primitive {
+            type Output = BigUint;
+            fn mul(self, rhs: BigUint) -> BigUint {
+                rhs * self
+            }
+        }
+
+        impl Mul<&BigUint> for $primitive {
+            type Output = BigUint;
+            fn mul(self, rhs: &BigUint) -> BigUint {
+                rhs.clone() * self
+            }
+        }
+
+        impl Div<$primitive> for BigUint {
+            type Output = BigUint;
+            fn div(self, rhs: $primitive) -> BigUint {
+                div_rem_primitive(&self, rhs as u128).0
+            }
+        }
+
+        impl Div<$primitive> for &BigUint {
+            type Output = BigUint;
+            fn div(self, rhs: $primitive) -> BigUint {
+                div_rem_primitive(self, rhs as u128).0
+            }
+        }
+
+        impl Div<BigUint> for $primitive {
+            type Output = BigUint;
+            fn div(self, rhs: BigUint) -> BigUint {
+                BigUint::from(self) / &rhs
+            }
+        }
+
+        impl Div<&BigUint> for $primitive {
+            type Output = BigUint;
+            fn div(self, rhs: &BigUint) -> BigUint {
+                BigUint::from(self) / rhs
+            }
+        }
+
+        impl Rem<$primitive> for BigUint {
+            type Output = BigUint;
+            fn rem(self, rhs: $primitive) -> BigUint {
+                div_rem_primitive(&self, rhs as u128).1
+            }
+        }
+
+        impl Rem<$primitive> for &BigUint {
+            type Output = BigUint;
+            fn rem(self, rhs: $primitive) -> BigUint {
+                div_rem_primitive(self, rhs as u128).1
+            }
+        }
+
+        impl Rem<BigUint> for $primitive {
+            type Output = BigUint;
+            fn rem(self, rhs: BigUint) -> BigUint {
+                BigUint::from(self) % &rhs
+            }
+        }
+
+        impl Rem<&BigUint> for $primitive {
+            type Output = BigUint;
+            fn rem(self, rhs: &BigUint) -> BigUint {
+                BigUint::from(self) % rhs
+            }
+        }
+    )*};
+}
+
+primitive_ops!(u8, u16, u32, u64, u128, usize);
+
+/// Converts an i32 operand, the type an unsuffixed literal falls back to
+/// when several primitive impls would fit it.
+///
+/// Panics if the value is negative.
+fn non_negative(value: i32) -> u32 {
+    u32::try_from(value).expect("attempt to combine a BigUint with a negative integer")
+}
+
+/// Implements the binary operators between a `BigUint` and an i32, on either
+/// side, by converting the i32 to a u32 and using the impls above.
+macro_rules! literal_ops {
+    ($($imp:ident, $method:ident);*) => {$(
+        impl $imp<i32> for BigUint {
+            type Output = BigUint;
+            fn $method(self, rhs: i32) -> BigUint {
+                self.$method(non_negative(rhs))
+            }
+        }
+
+        impl $imp<i32> for &BigUint {
+            type Output = BigUint;
+            fn $method(self, rhs: i32) -> BigUint {
+                self.$method(non_negative(rhs))
+            }
+        }
+
+        impl $imp<BigUint> for i32 {
+            type Output = BigUint;
+            fn $method(self, rhs: BigUint) -> BigUint {
+                non_negative(self).$method(rhs)
+            }
+        }
+
+        impl $imp<&BigUint> for i32 {
+            type Output = BigUint;
+            fn $method(self, rhs: &BigUint) -> BigUint {
+                non_negative(self).$method(rhs)
+            }
+        }
+    )*};
+}
+
+literal_ops!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+// The assigning operators only take a u32, like the `MulAssign<u32>` they sit
+// beside. With an impl for every width, an unsuffixed literal such as the `2`
+// in `number *= 2` would match several of them, fall back to i32 and fail to
+// compile. Wider values can be converted with `BigUint::from` first.
+
+impl AddAssign<u32> for BigUint {
+    fn add_assign(&mut self, rhs: u32) {
+        *self += &BigUint::from(rhs);
+    }
+}
+
+impl SubAssign<u32> for BigUint {
+    fn sub_assign(&mut self, rhs: u32) {
+        *self -= &BigUint::from(rhs);
+    }
+}
+
+impl DivAssign<u32> for BigUint {
+    fn div_assign(&mut self, rhs: u32) {
+        *self = div_rem_primitive(self, u128::from(rhs)).0;
+    }
+}
+
+impl RemAssign<u32> for BigUint {
+    fn rem_assign(&mut self, rhs: u32) {
+        *self = div_rem_primitive(self, u128::from(rhs)).1;
+    }
+}
@@ -0,0 +1,153 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+use prime;
+use BigUint;
+
+/// This trait represents x^y % m
+///
+/// # Panics
+/// Every implementation panics if the modulus is zero.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// assert_eq!(445, 4_u32.pow_mod(13, 497));
+/// assert_eq!(1, 3_u64.pow_mod(998_244_352, 998_244_353));
+/// let modulus = BigUint::new("1,000,000,007");
+/// // 2^(p - 2) is the inverse of 2 modulo a prime p.
+/// assert_eq!(BigUint::new("500,000,004"), (&BigUint::from(2_u32)).pow_mod(&BigUint::new("1,000,000,005"), &modulus));
+/// assert_eq!(BigUint::new("445"), BigUint::new("4").pow_mod(BigUint::new("13"), BigUint::new("497")));
+/// ```
+pub trait PowMod<Exponent = Self, Modulus = Self> {
+    type Output;
+    fn pow_mod(self, exponent: Exponent, modulus: Modulus) -> Self::Output;
+}
+
+impl PowMod for u32 {
+    type Output = u32;
+    fn pow_mod(self, exponent: u32, modulus: u32) -> u32 {
+        assert!(0 != modulus, "modulus must be non-zero");
+        prime::pow_mod(u64::from(self), u64::from(exponent), u64::from(modulus)) as u32
+    }
+}
+
+impl PowMod for u64 {
+    type Output = u64;
+    fn pow_mod(self, exponent: u64, modulus: u64) -> u64 {
+        assert!(0 != modulus, "modulus must be non-zero");
+        prime::pow_mod(self, exponent, modulus)
+    }
+}
+
+impl PowMod for u128 {
+    type Output = u128;
+    fn pow_mod(self, exponent: u128, modulus: u128) -> u128 {
+        assert!(0 != modulus, "modulus must be non-zero");
+        let bits = (0..128 - exponent.leading_zeros())
+            .map(|bit| 1 == (exponent >> bit) & 1)
+            .collect::<Vec<bool>>();
+        sliding_window(self % modulus, 1 % modulus, &bits, |lhs, rhs| {
+            mul_mod_u128(*lhs, *rhs, modulus)
+        })
+    }
+}
+
+/// Forwards to `BigUint::modpow`. The buckets hold decimal digits, so Montgomery
+/// reduction needs a modulus coprime to 10: odd multiples of 5 are reduced with
+/// Barrett reduction, like even moduli.
+impl<'a> PowMod<&'a BigUint, &'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+    fn pow_mod(self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        self.modpow(exponent, modulus)
+    }
+}
+
+impl PowMod for BigUint {
+    type Output = BigUint;
+    fn pow_mod(self, exponent: BigUint, modulus: BigUint) -> BigUint {
+        (&self).pow_mod(&exponent, &modulus)
+    }
+}
+
+/// Calculates `lhs * rhs % modulus` for residues below the modulus, where the product
+/// may not fit in a u128, by doubling and adding one bit of `rhs` at a time.
+fn mul_mod_u128(mut lhs: u128, mut rhs: u128, modulus: u128) -> u128 {
+    // Both operands are below the modulus, so `modulus - rhs` cannot underflow.
+    let add_mod = |lhs: u128, rhs: u128| {
+        if lhs >= modulus - rhs {
+            lhs - (modulus - rhs)
+        } else {
+            lhs + rhs
+        }
+    };
+    let mut result = 0;
+    while 0 != rhs {
+        if 1 == rhs & 1 {
+            result = add_mod(result, lhs);
+        }
+        lhs = add_mod(lhs, lhs);
+        rhs >>= 1;
+    }
+    result
+}
+
+/// Picks the window size that minimises the number of multiplications
+/// for an exponent of the given bit length.
+fn window_size(bits: usize) -> usize {
+    match bits {
+        0..=24 => 1,
+        25..=80 => 3,
+        81..=240 => 4,
+        241..=672 => 5,
+        _ => 6,
+    }
+}
+
+/// Left-to-right sliding-window exponentiation.
+///
+/// `bits` are the bits of the exponent, least significant first, and `mul` is the
+/// modular multiplication in whatever representation `base` and `one` are in.
+/// The odd powers `base^1, base^3, ..., base^(2^w - 1)` are precomputed, and the
+/// exponent is scanned from the top: zeros cost one squaring each, and each window
+/// of up to `w` bits that starts and ends with a one costs its squarings plus a
+/// single multiplication by a precomputed power.
+pub(crate) fn sliding_window<T, F>(base: T, one: T, bits: &[bool], mul: F) -> T
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    let window = window_size(bits.len());
+    let square = mul(&base, &base);
+    let mut odd_powers = vec![base];
+    for index in 1..1 << (window - 1) {
+        let next = mul(&odd_powers[index - 1], &square);
+        odd_powers.push(next);
+    }
+
+    let mut result = one;
+    let mut top = bits.len();
+    while 0 < top {
+        if !bits[top - 1] {
+            result = mul(&result, &result);
+            top -= 1;
+            continue;
+        }
+        // The longest window ending at `top - 1` whose lowest bit is also set.
+        let mut bottom = top.saturating_sub(window);
+        while !bits[bottom] {
+            bottom += 1;
+        }
+        let value = (bottom..top).rev().fold(0, |value, bit| 2 * value + bits[bit] as usize);
+        for _ in bottom..top {
+            result = mul(&result, &result);
+        }
+        result = mul(&result, &odd_powers[value / 2]);
+        top = bottom;
+    }
+    result
+}
@@ -0,0 +1,182 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+/// The first twelve primes. Using each of these as a Miller-Rabin witness
+/// makes the test deterministic for every number below 3.3 * 10^24,
+/// which comfortably covers every `u64`.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Sieve of Eratosthenes: returns every prime strictly less than `limit`.
+pub(crate) fn primes_below(limit: usize) -> Vec<u64> {
+    let mut is_composite = vec![false; limit];
+    let mut primes = Vec::new();
+    for number in 2..limit {
+        if !is_composite[number] {
+            primes.push(number as u64);
+            for multiple in (number * number..limit).step_by(number) {
+                is_composite[multiple] = true;
+            }
+        }
+    }
+    primes
+}
+
+/// Greatest common divisor of two machine words, by Euclid's algorithm.
+pub(crate) fn gcd(mut lhs: u64, mut rhs: u64) -> u64 {
+    while 0 != rhs {
+        let remainder = lhs % rhs;
+        lhs = rhs;
+        rhs = remainder;
+    }
+    lhs
+}
+
+/// Calculates `lhs * rhs % modulus` without overflowing.
+/// The product is widened to u128 so that any u64 modulus is safe.
+#[inline]
+pub(crate) fn mul_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    (u128::from(lhs) * u128::from(rhs) % u128::from(modulus)) as u64
+}
+
+/// Calculates `base^exponent % modulus` by repeated squaring.
+pub(crate) fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while 0 < exponent {
+        if 1 == exponent & 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Calculates the Jacobi symbol `(a / n)` for an odd, positive `n`.
+/// Returns `0` when `a` and `n` share a factor, otherwise `1` or `-1`.
+pub(crate) fn jacobi(mut a: u64, mut n: u64) -> i32 {
+    debug_assert!(1 == n & 1);
+    let mut result = 1;
+    a %= n;
+    while 0 != a {
+        while 0 == a & 1 {
+            a >>= 1;
+            if 3 == n % 8 || 5 == n % 8 {
+                result = -result;
+            }
+        }
+        ::std::mem::swap(&mut a, &mut n);
+        if 3 == a % 4 && 3 == n % 4 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if 1 == n {
+        result
+    } else {
+        0
+    }
+}
+
+/// Performs a single round of the Miller-Rabin test on an odd `number`,
+/// where `number - 1 == d * 2^s` and `d` is odd.
+fn is_strong_probable_prime(number: u64, d: u64, s: u32, witness: u64) -> bool {
+    let mut x = pow_mod(witness, d, number);
+    if 1 == x || number - 1 == x {
+        return true;
+    }
+    for _ in 1..s {
+        x = mul_mod(x, x, number);
+        if number - 1 == x {
+            return true;
+        }
+    }
+    false
+}
+
+/// Deterministic primality test for machine words.
+///
+/// Small factors are ruled out by trial division, then a Miller-Rabin
+/// test is run with each of the first twelve primes as a witness.
+/// No composite below 3.3 * 10^24 passes all twelve rounds, so the
+/// answer is exact for every `u64`.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// assert!(is_prime(998_244_353));
+/// assert!(is_prime(18_446_744_073_709_551_557));
+/// assert!(!is_prime(3_215_031_751)); // 151 * 751 * 28351
+/// ```
+pub fn is_prime(number: u64) -> bool {
+    if 2 > number {
+        return false;
+    }
+    for &prime in WITNESSES.iter() {
+        if number == prime {
+            return true;
+        }
+        if number.is_multiple_of(prime) {
+            return false;
+        }
+    }
+
+    let s = (number - 1).trailing_zeros();
+    let d = (number - 1) >> s;
+    WITNESSES
+        .iter()
+        .all(|&witness| is_strong_probable_prime(number, d, s, witness))
+}
+
+/// Returns the smallest prime strictly greater than `number`,
+/// or `None` if that prime does not fit in a `u64`.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// assert_eq!(Some(2), next_prime(0));
+/// assert_eq!(Some(101), next_prime(97));
+/// assert_eq!(None, next_prime(18_446_744_073_709_551_557));
+/// ```
+pub fn next_prime(number: u64) -> Option<u64> {
+    if 2 > number {
+        return Some(2);
+    }
+    // Step through the odd numbers only.
+    let mut candidate = number.checked_add(1)? | 1;
+    while !is_prime(candidate) {
+        candidate = candidate.checked_add(2)?;
+    }
+    Some(candidate)
+}
+
+/// Returns the largest prime strictly less than `number`,
+/// or `None` if there is no such prime (`number <= 2`).
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// assert_eq!(None, prev_prime(2));
+/// assert_eq!(Some(2), prev_prime(3));
+/// assert_eq!(Some(97), prev_prime(101));
+/// ```
+pub fn prev_prime(number: u64) -> Option<u64> {
+    if 3 > number {
+        return None;
+    }
+    if 3 == number {
+        return Some(2);
+    }
+    // Step through the odd numbers only.
+    let mut candidate = (number - 2) | 1;
+    while !is_prime(candidate) {
+        candidate -= 2;
+    }
+    Some(candidate)
+}
@@ -0,0 +1,536 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+use biguint::div_rem;
+use std::cmp::{max, Ordering};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use std::str::FromStr;
+use {BigUint, ParseBigIntError};
+
+/// An exact fraction of arbitrary size.
+///
+/// A `BigUint` cannot be negative, so the value is stored as a sign and the
+/// magnitudes of the numerator and denominator. Every value is kept in lowest
+/// terms with a positive denominator, and zero is never negative, so two
+/// equal fractions always have the same representation.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// let half = BigRational::new(BigUint::new("1"), BigUint::new("2"));
+/// let third = BigRational::new(BigUint::new("1"), BigUint::new("3"));
+/// assert_eq!(BigRational::new(BigUint::new("5"), BigUint::new("6")), &half + &third);
+/// assert_eq!("1/6", (&half - &third).to_string());
+/// assert_eq!("-1/6", (&third - &half).to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigRational {
+    negative: bool,
+    numerator: BigUint,
+    denominator: BigUint,
+}
+
+impl BigRational {
+    /// Creates the non-negative fraction `numerator / denominator`, reduced to lowest terms.
+    ///
+    /// # Panics
+    /// Panics if the denominator is zero.
+    pub fn new(numerator: BigUint, denominator: BigUint) -> BigRational {
+        BigRational::reduced(false, numerator, denominator)
+    }
+
+    /// Creates the rational zero.
+    pub fn zero() -> BigRational {
+        BigRational::from(BigUint::zero())
+    }
+
+    /// Creates the rational one.
+    pub fn one() -> BigRational {
+        BigRational::from(BigUint::one())
+    }
+
+    /// Returns the magnitude of the numerator.
+    pub fn numerator(&self) -> &BigUint {
+        &self.numerator
+    }
+
+    /// Returns the denominator, which is always positive.
+    pub fn denominator(&self) -> &BigUint {
+        &self.denominator
+    }
+
+    /// Returns true if the value is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns true if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    /// Returns true if the denominator is one.
+    pub fn is_integer(&self) -> bool {
+        BigUint::one() == self.denominator
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> BigRational {
+        BigRational {
+            negative: false,
+            ..self.clone()
+        }
+    }
+
+    /// Returns `1 / self`.
+    ///
+    /// # Panics
+    /// Panics if the value is zero.
+    pub fn recip(&self) -> BigRational {
+        assert!(!self.is_zero(), "cannot take the reciprocal of zero");
+        BigRational {
+            negative: self.negative,
+            numerator: self.denominator.clone(),
+            denominator: self.numerator.clone(),
+        }
+    }
+
+    /// Raises the fraction to an integer power. Both parts are already coprime,
+    /// so their powers are too and no reduction is needed.
+    ///
+    /// # Panics
+    /// Panics if zero is raised to a negative power.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let two_thirds = BigRational::new(BigUint::new("2"), BigUint::new("3"));
+    /// assert_eq!("8/27", two_thirds.pow(3).to_string());
+    /// assert_eq!("9/4", two_thirds.pow(-2).to_string());
+    /// ```
+    pub fn pow(&self, exponent: i32) -> BigRational {
+        let base = if exponent < 0 { self.recip() } else { self.clone() };
+        let exponent = exponent.unsigned_abs();
+        BigRational {
+            negative: base.negative && 1 == exponent % 2,
+            numerator: base.numerator.pow(exponent),
+            denominator: base.denominator.pow(exponent),
+        }
+    }
+
+    /// Rounds towards negative infinity.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let value: BigRational = "-7/2".parse().unwrap();
+    /// assert_eq!(BigRational::from(-4_i64), value.floor());
+    /// assert_eq!(BigRational::from(-3_i64), value.ceil());
+    /// assert_eq!(BigRational::from(-4_i64), value.round());
+    /// assert_eq!(BigRational::from(-3_i64), value.trunc());
+    /// ```
+    pub fn floor(&self) -> BigRational {
+        let (quotient, remainder) = div_rem(&self.numerator, &self.denominator);
+        if self.negative && !remainder.is_zero() {
+            BigRational::integer(true, quotient + 1_u8)
+        } else {
+            BigRational::integer(self.negative, quotient)
+        }
+    }
+
+    /// Rounds towards positive infinity.
+    pub fn ceil(&self) -> BigRational {
+        -(-self).floor()
+    }
+
+    /// Rounds towards zero.
+    pub fn trunc(&self) -> BigRational {
+        BigRational::integer(self.negative, &self.numerator / &self.denominator)
+    }
+
+    /// Rounds to the nearest integer, with halves rounded away from zero.
+    pub fn round(&self) -> BigRational {
+        // floor(|n / d| + 1/2) = floor((2n + d) / 2d)
+        let doubled = &self.denominator * 2_u8;
+        BigRational::integer(self.negative, (&self.numerator * 2_u8 + &self.denominator) / doubled)
+    }
+
+    /// Converts an `f64` exactly, since every finite float is a fraction with
+    /// a power of two as its denominator. Returns `None` for infinities and NaN.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// assert_eq!(Some("-3/8".parse().unwrap()), BigRational::from_f64(-0.375));
+    /// assert_eq!(None, BigRational::from_f64(f64::NAN));
+    /// ```
+    pub fn from_f64(value: f64) -> Option<BigRational> {
+        if !value.is_finite() {
+            return None;
+        }
+        let bits = value.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if 0 == biased {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+        let mantissa = BigUint::from(mantissa);
+        let rational = if exponent < 0 {
+            BigRational::new(mantissa, BigUint::one() << (-exponent) as usize)
+        } else {
+            BigRational::from(mantissa << exponent as usize)
+        };
+        Some(if 1 == bits >> 63 { -rational } else { rational })
+    }
+
+    /// Converts to the nearest `f64`, with ties to even. Values too large for an
+    /// `f64` become infinite.
+    ///
+    /// As in `BigFloat::to_f64`, the value is rounded once, straight to the last bit
+    /// the `f64` keeps: the numerator is divided by the denominator scaled to that bit,
+    /// and the remainder of the division decides the rounding.
+    pub fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let scaled = |number: &BigUint, shift: i64| {
+            if 0 <= shift {
+                number << shift as usize
+            } else {
+                number.clone()
+            }
+        };
+        // The value is in `[2^(top - 1), 2^top)`, and `top` is one of these two.
+        let mut top = self.numerator.bits() as i64 - self.denominator.bits() as i64;
+        if scaled(&self.numerator, -top) >= scaled(&self.denominator, top) {
+            top += 1;
+        }
+        let magnitude = if 1_100 < top {
+            f64::INFINITY
+        } else if top < -1_100 {
+            0.0
+        } else {
+            let last = max(top - 53, -1_074);
+            let divisor = scaled(&self.denominator, last);
+            let (quotient, remainder) = div_rem(&scaled(&self.numerator, -last), &divisor);
+            let round_up = match (remainder * 2_u8).cmp(&divisor) {
+                Ordering::Less => false,
+                Ordering::Equal => !quotient.is_even(),
+                Ordering::Greater => true,
+            };
+            // At most 2^53, which an f64 still holds exactly.
+            let mantissa = quotient.to_u64().unwrap_or(0) + u64::from(round_up);
+            scale_by_power_of_two(mantissa as f64, last)
+        };
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Writes the value as a decimal, with any repeating block in parentheses,
+    /// e.g. `0.(3)` for one third and `-1.1(6)` for minus seven sixths.
+    ///
+    /// After the factors of two and five are taken out of the denominator,
+    /// what remains is coprime to ten, so the remainders of the long division
+    /// must eventually return to the first one after the non-repeating digits.
+    /// The repeating block can be almost as long as the denominator.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate nordint;
+    /// use nordint::*;
+    /// let value: BigRational = "1/7".parse().unwrap();
+    /// assert_eq!("0.(142857)", value.to_decimal_string());
+    /// let value: BigRational = "-5/4".parse().unwrap();
+    /// assert_eq!("-1.25", value.to_decimal_string());
+    /// ```
+    pub fn to_decimal_string(&self) -> String {
+        let (quotient, mut remainder) = div_rem(&self.numerator, &self.denominator);
+        let mut decimal = if self.negative { String::from("-") } else { String::new() };
+        decimal += &quotient.to_string();
+        if remainder.is_zero() {
+            return decimal;
+        }
+        decimal.push('.');
+
+        let mut coprime = self.denominator.clone();
+        let (mut twos, mut fives) = (0, 0);
+        while coprime.is_even() {
+            coprime = coprime.div_rem_small(2).0;
+            twos += 1;
+        }
+        while 0 == coprime.div_rem_small(5).1 {
+            coprime = coprime.div_rem_small(5).0;
+            fives += 1;
+        }
+
+        for _ in 0..twos.max(fives) {
+            decimal += &self.next_digit(&mut remainder).to_string();
+        }
+        if remainder.is_zero() {
+            return decimal;
+        }
+        let first = remainder.clone();
+        decimal.push('(');
+        loop {
+            decimal += &self.next_digit(&mut remainder).to_string();
+            if remainder == first {
+                break;
+            }
+        }
+        decimal.push(')');
+        decimal
+    }
+
+    /// Takes one step of long division by the denominator, returning the next digit.
+    fn next_digit(&self, remainder: &mut BigUint) -> BigUint {
+        let (digit, next) = div_rem(&(&*remainder * 10_u8), &self.denominator);
+        *remainder = next;
+        digit
+    }
+
+    /// Reduces `numerator / denominator` to lowest terms.
+    fn reduced(negative: bool, numerator: BigUint, denominator: BigUint) -> BigRational {
+        assert!(!denominator.is_zero(), "denominator must be non-zero");
+        let (numerator, denominator) = (numerator.trimmed(), denominator.trimmed());
+        let gcd = numerator.gcd(&denominator);
+        let (numerator, denominator) = if BigUint::one() == gcd {
+            (numerator, denominator)
+        } else {
+            (&numerator / &gcd, &denominator / &gcd)
+        };
+        BigRational {
+            negative: negative && !numerator.is_zero(),
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Wraps a signed integer.
+    fn integer(negative: bool, magnitude: BigUint) -> BigRational {
+        BigRational {
+            negative: negative && !magnitude.is_zero(),
+            numerator: magnitude.trimmed(),
+            denominator: BigUint::one(),
+        }
+    }
+}
+
+/// Multiplies by `2^exponent` in steps small enough that no step overflows on its own.
+pub(crate) fn scale_by_power_of_two(mut value: f64, mut exponent: i64) -> f64 {
+    while 1_000 < exponent {
+        value *= 2_f64.powi(1_000);
+        exponent -= 1_000;
+    }
+    while exponent < -1_000 {
+        value *= 2_f64.powi(-1_000);
+        exponent += 1_000;
+    }
+    value * 2_f64.powi(exponent as i32)
+}
+
+/// Adds two signed magnitudes.
+pub(crate) fn signed_add(lhs_negative: bool, lhs: BigUint, rhs_negative: bool, rhs: BigUint) -> (bool, BigUint) {
+    if lhs_negative == rhs_negative {
+        (lhs_negative, lhs + rhs)
+    } else if Ordering::Less == lhs.cmp(&rhs) {
+        (rhs_negative, rhs - lhs)
+    } else {
+        (lhs_negative, lhs - rhs)
+    }
+}
+
+impl Default for BigRational {
+    fn default() -> BigRational {
+        BigRational::zero()
+    }
+}
+
+impl From<BigUint> for BigRational {
+    fn from(integer: BigUint) -> BigRational {
+        BigRational::integer(false, integer)
+    }
+}
+
+impl From<u64> for BigRational {
+    fn from(integer: u64) -> BigRational {
+        BigRational::from(BigUint::from(integer))
+    }
+}
+
+impl From<i64> for BigRational {
+    fn from(integer: i64) -> BigRational {
+        BigRational::integer(integer < 0, BigUint::from(integer.unsigned_abs()))
+    }
+}
+
+impl Ord for BigRational {
+    /// Compares by sign, and then by cross-multiplying the magnitudes.
+    fn cmp(&self, other: &BigRational) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (negative, _) => {
+                let ordering = (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator));
+                if negative {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for BigRational {
+    fn partial_cmp(&self, other: &BigRational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigRational {
+    /// Formats as `numerator/denominator`, or as an integer if the denominator is one.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        if self.is_integer() {
+            write!(f, "{}{}", sign, self.numerator)
+        } else {
+            write!(f, "{}{}/{}", sign, self.numerator, self.denominator)
+        }
+    }
+}
+
+impl FromStr for BigRational {
+    type Err = ParseBigIntError;
+
+    /// Parses a fraction such as `-22/7`, an integer, or a decimal such as `3.14`
+    /// whose repeating block, if any, is written in parentheses: `0.1(6)`.
+    fn from_str(rational: &str) -> Result<BigRational, ParseBigIntError> {
+        let (negative, unsigned) = match rational.as_bytes().first() {
+            Some(b'-') => (true, &rational[1..]),
+            Some(b'+') => (false, &rational[1..]),
+            _ => (false, rational),
+        };
+        let parse = |digits: &str| BigUint::from_str(digits).map(|number| number.trimmed());
+        if let Some(slash) = unsigned.find('/') {
+            let denominator = parse(&unsigned[slash + 1..])?;
+            if denominator.is_zero() {
+                return Err(ParseBigIntError::zero_denominator());
+            }
+            return Ok(BigRational::reduced(negative, parse(&unsigned[..slash])?, denominator));
+        }
+
+        let (integer, fraction) = match unsigned.find('.') {
+            Some(point) => (&unsigned[..point], &unsigned[point + 1..]),
+            None => (unsigned, ""),
+        };
+        let (fixed, repeating) = match fraction.find('(') {
+            Some(open) if fraction.ends_with(')') => (&fraction[..open], &fraction[open + 1..fraction.len() - 1]),
+            Some(_) => return Err(ParseBigIntError::invalid()),
+            None => (fraction, ""),
+        };
+        let digits_only = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        let missing_digits = (fixed.is_empty() && repeating.is_empty() && unsigned.contains('.'))
+            || (repeating.is_empty() && fraction.contains('('));
+        if missing_digits || !digits_only(integer) || !digits_only(fixed) || !digits_only(repeating) {
+            return Err(ParseBigIntError::invalid());
+        }
+
+        // With the repeating block `r` of length `k` after the fixed digits `f`,
+        // x = (ifr - if) / (10^|f| * (10^k - 1)), where `if` and `ifr` are the
+        // digits of the integer part followed by the others.
+        let leading = parse(&format!("{}{}", integer, fixed))?;
+        let scale = BigUint::pow_of_ten(fixed.len());
+        if repeating.is_empty() {
+            return Ok(BigRational::reduced(negative, leading, scale));
+        }
+        let all = parse(&format!("{}{}{}", integer, fixed, repeating))?;
+        let nines = BigUint::pow_of_ten(repeating.len()) - 1_u8;
+        Ok(BigRational::reduced(negative, all - leading, scale * nines))
+    }
+}
+
+impl Neg for BigRational {
+    type Output = BigRational;
+    fn neg(self) -> BigRational {
+        BigRational {
+            negative: !self.negative && !self.is_zero(),
+            ..self
+        }
+    }
+}
+
+impl Neg for &BigRational {
+    type Output = BigRational;
+    fn neg(self) -> BigRational {
+        -self.clone()
+    }
+}
+
+impl Add<&BigRational> for &BigRational {
+    type Output = BigRational;
+    fn add(self, rhs: &BigRational) -> BigRational {
+        let (negative, numerator) = signed_add(
+            self.negative,
+            &self.numerator * &rhs.denominator,
+            rhs.negative,
+            &rhs.numerator * &self.denominator,
+        );
+        BigRational::reduced(negative, numerator, &self.denominator * &rhs.denominator)
+    }
+}
+
+impl Sub<&BigRational> for &BigRational {
+    type Output = BigRational;
+    fn sub(self, rhs: &BigRational) -> BigRational {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigRational> for &BigRational {
+    type Output = BigRational;
+    fn mul(self, rhs: &BigRational) -> BigRational {
+        BigRational::reduced(
+            self.negative != rhs.negative,
+            &self.numerator * &rhs.numerator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Div<&BigRational> for &BigRational {
+    type Output = BigRational;
+    /// Panics if the divisor is zero.
+    fn div(self, rhs: &BigRational) -> BigRational {
+        assert!(!rhs.is_zero(), "attempt to divide a BigRational by zero");
+        self * &rhs.recip()
+    }
+}
+
+impl Rem<&BigRational> for &BigRational {
+    type Output = BigRational;
+    /// The remainder of truncated division, which takes the sign of the dividend.
+    ///
+    /// Panics if the divisor is zero.
+    fn rem(self, rhs: &BigRational) -> BigRational {
+        self - &(rhs * &(self / rhs).trunc())
+    }
+}
+
+forward_to_borrowed!(BigRational, Add, add, AddAssign, add_assign);
+forward_to_borrowed!(BigRational, Sub, sub, SubAssign, sub_assign);
+forward_to_borrowed!(BigRational, Mul, mul, MulAssign, mul_assign);
+forward_to_borrowed!(BigRational, Div, div, DivAssign, div_assign);
+forward_to_borrowed!(BigRational, Rem, rem, RemAssign, rem_assign);
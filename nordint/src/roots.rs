@@ -0,0 +1,112 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+use factor::factorize;
+use prime::{self, gcd, pow_mod};
+use RootOfUnityError;
+
+/// Calculates the multiplicative order of `value` modulo `modulus`:
+/// the smallest `k > 0` such that `value^k = 1 (mod modulus)`.
+///
+/// The order always divides Euler's totient of the modulus, so we start from
+/// the totient and divide out each of its prime factors for as long as the
+/// power still comes back to one.
+///
+/// Returns `None` when `value` and `modulus` share a factor, since no power
+/// of `value` can then be one.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// assert_eq!(Some(6), multiplicative_order(3, 7));
+/// assert_eq!(Some(3), multiplicative_order(2, 7));
+/// assert_eq!(Some(2), multiplicative_order(5, 12));
+/// assert_eq!(None, multiplicative_order(4, 12));
+/// ```
+pub fn multiplicative_order(value: u64, modulus: u64) -> Option<u64> {
+    if 0 == modulus || 1 != gcd(value, modulus) {
+        return None;
+    }
+    if 1 == modulus {
+        return Some(1);
+    }
+    let totient = factorize(modulus).totient();
+    let mut order = totient;
+    for &(factor, _) in factorize(totient).factors() {
+        while order.is_multiple_of(factor) && 1 == pow_mod(value, order / factor, modulus) {
+            order /= factor;
+        }
+    }
+    Some(order)
+}
+
+/// Finds the smallest primitive root (generator) of a prime modulus `p`.
+///
+/// Every element's order divides `p - 1`, so `g` generates the whole group
+/// exactly when its order is not a proper divisor of `p - 1`. Any proper divisor
+/// divides `(p - 1) / q` for some prime `q | p - 1`, so it suffices to check that
+/// `g^((p - 1) / q) != 1` for each such `q`.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// assert_eq!(Ok(3), primitive_root(7));
+/// assert_eq!(Ok(3), primitive_root(998_244_353));
+/// assert_eq!(Err(RootOfUnityError::ModulusNotPrime(8)), primitive_root(8));
+/// ```
+pub fn primitive_root(modulus: u64) -> Result<u64, RootOfUnityError> {
+    if !prime::is_prime(modulus) {
+        return Err(RootOfUnityError::ModulusNotPrime(modulus));
+    }
+    if 2 == modulus {
+        return Ok(1);
+    }
+    let max_value = modulus - 1;
+    let factorization = factorize(max_value);
+    let generator = (2..modulus)
+        .find(|generator| {
+            factorization
+                .primes()
+                .iter()
+                .all(|factor| 1 != pow_mod(*generator, max_value / **factor, modulus))
+        })
+        .expect("the multiplicative group of a prime field is cyclic");
+    Ok(generator)
+}
+
+/// Finds a primitive `n`th root of unity modulo a prime `p`: an `omega`
+/// such that `omega^n = 1` but `omega^k != 1` for every `0 < k < n`.
+///
+/// The multiplicative group modulo `p` is cyclic of order `p - 1`,
+/// so such a root exists exactly when `n` divides `p - 1`.
+/// It is found by raising a primitive root `g` to the power `(p - 1) / n`.
+///
+/// # Example
+/// ```
+/// extern crate nordint;
+/// use nordint::*;
+/// let omega = root_of_unity(8, 17).unwrap();
+/// assert_eq!(Some(8), multiplicative_order(omega, 17));
+/// assert_eq!(
+///     Err(RootOfUnityError::OrderDoesNotDivide { order: 5, modulus: 17 }),
+///     root_of_unity(5, 17)
+/// );
+/// ```
+pub fn root_of_unity(n: u64, modulus: u64) -> Result<u64, RootOfUnityError> {
+    if 0 == n {
+        return Err(RootOfUnityError::ZeroOrder);
+    }
+    if !prime::is_prime(modulus) {
+        return Err(RootOfUnityError::ModulusNotPrime(modulus));
+    }
+    if !(modulus - 1).is_multiple_of(n) {
+        return Err(RootOfUnityError::OrderDoesNotDivide { order: n, modulus });
+    }
+    let generator = primitive_root(modulus)?;
+    Ok(pow_mod(generator, (modulus - 1) / n, modulus))
+}
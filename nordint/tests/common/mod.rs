@@ -0,0 +1,19 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+//! Fixtures shared by the integration tests.
+
+/// Generates `len` random non-zero digits from a fixed xorshift sequence.
+pub fn random_digits(state: &mut u64, len: usize) -> String {
+    (0..len)
+        .map(|_| {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            char::from(b'1' + (*state % 9) as u8)
+        })
+        .collect()
+}
@@ -7,6 +7,9 @@
 #![feature(test)]
 
 extern crate nordint;
+extern crate num_bigint;
+
+mod common;
 
 #[cfg(test)]
 mod biguint_simple_constructors {
@@ -461,4 +464,800 @@ mod biguint_mul_assign_biguint {
         let expected = BigUint::new("23,807,094,487,977,417,195,524,266,316,260,245,358,675,922,650,621,440,574,649,097,434,648,265,751,573,598,736,641,889,579,856,426,908,359,061,416,704,770,573,997,866,200,731,595,789,971,041");
         assert_eq!(actual, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn schonhage_strassen_random_lengths() {
+        use common::random_digits;
+        use num_bigint::BigUint as CrateBigUint;
+        use std::str::FromStr;
+
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        for &(lhs_len, rhs_len) in &[(1, 1), (3, 7), (17, 250), (101, 99), (640, 360), (1_000, 1_001), (2_345, 17)] {
+            let lhs = random_digits(&mut state, lhs_len);
+            let rhs = random_digits(&mut state, rhs_len);
+            let expected = CrateBigUint::from_str(&lhs).unwrap() * CrateBigUint::from_str(&rhs).unwrap();
+            let actual = &BigUint::new(&lhs) * &BigUint::new(&rhs);
+            assert_eq!(expected.to_string(), actual.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod biguint_primality {
+    use nordint::BigUint;
+
+    #[test]
+    fn empty_and_small() {
+        assert!(!BigUint::empty().is_probable_prime(0));
+        assert!(!BigUint::zero().is_probable_prime(0));
+        assert!(!BigUint::one().is_probable_prime(0));
+        assert!(BigUint::from(2_u32).is_probable_prime(0));
+        assert!(BigUint::from(97_u32).is_probable_prime(0));
+        assert!(!BigUint::from(561_u32).is_probable_prime(0));
+    }
+
+    #[test]
+    fn mersenne_primes() {
+        // 2^89 - 1 and 2^127 - 1
+        assert!(BigUint::new("618970019642690137449562111").is_probable_prime(10));
+        assert!(BigUint::new("170141183460469231731687303715884105727").is_probable_prime(10));
+    }
+
+    #[test]
+    fn many_rounds() {
+        // More rounds than there are small primes, each with its own witness.
+        let prime = BigUint::new("170141183460469231731687303715884105727");
+        assert!(prime.is_probable_prime(100));
+        assert!(!(&prime * &prime).is_probable_prime(100));
+    }
+
+    #[test]
+    fn composites_beyond_u64() {
+        // (2^61 - 1) * (2^89 - 1)
+        assert!(!BigUint::new("1427247692705959880439315947500961989719490561").is_probable_prime(0));
+        // Carmichael number (6k + 1)(12k + 1)(18k + 1) with k = 242396
+        assert!(!BigUint::new("18457883288813385649").is_probable_prime(0));
+        // Carmichael number with k = 243746, also a strong pseudoprime to base 2
+        assert!(!BigUint::new("18768001878618448249").is_probable_prime(0));
+        // (10^30 + 57)^2
+        let prime = BigUint::new("1000000000000000000000000000057");
+        let square = BigUint::new("1000000000000000000000000000114000000000000000000000000003249");
+        assert!(prime.is_probable_prime(0));
+        assert!(!square.is_probable_prime(0));
+    }
+
+    #[test]
+    fn next_prime() {
+        assert_eq!(BigUint::from(2_u32), BigUint::zero().next_prime());
+        assert_eq!(BigUint::from(101_u32), BigUint::from(97_u32).next_prime());
+        assert_eq!(
+            BigUint::new("18446744073709551629"),
+            BigUint::new("18446744073709551557").next_prime()
+        );
+        assert_eq!(
+            BigUint::new("1000000000000000000000000000057"),
+            BigUint::new("1000000000000000000000000000000").next_prime()
+        );
+    }
+
+    #[test]
+    fn prev_prime() {
+        assert_eq!(None, BigUint::zero().prev_prime());
+        assert_eq!(None, BigUint::from(2_u32).prev_prime());
+        assert_eq!(Some(BigUint::from(2_u32)), BigUint::from(3_u32).prev_prime());
+        assert_eq!(
+            Some(BigUint::new("18446744073709551557")),
+            BigUint::new("18446744073709551616").prev_prime()
+        );
+        assert_eq!(
+            Some(BigUint::new("999999999999999999999999999989")),
+            BigUint::new("1000000000000000000000000000000").prev_prime()
+        );
+    }
+
+    #[test]
+    fn ordering_is_numeric() {
+        assert!(BigUint::new("101") > BigUint::new("2"));
+        assert!(BigUint::new("99") < BigUint::new("100"));
+        assert!(BigUint::new("1234") < BigUint::new("1243"));
+        assert_eq!(
+            ::std::cmp::Ordering::Equal,
+            BigUint::zero().cmp(&BigUint::empty())
+        );
+    }
+
+    #[test]
+    fn equality_agrees_with_ordering() {
+        // "000" parses to no buckets at all, while zero() holds a single zero bucket.
+        let untrimmed = BigUint::new("000");
+        assert_ne!(format!("{:?}", BigUint::zero()), format!("{:?}", untrimmed));
+        assert_eq!(BigUint::zero(), untrimmed);
+
+        let mut numbers = vec![BigUint::new("7"), BigUint::zero(), untrimmed, BigUint::new("7")];
+        numbers.sort();
+        numbers.dedup();
+        assert_eq!(vec![BigUint::zero(), BigUint::new("7")], numbers);
+        let set = numbers.into_iter().collect::<::std::collections::BTreeSet<BigUint>>();
+        assert!(set.contains(&BigUint::new("0")));
+    }
+}
+
+#[cfg(test)]
+mod biguint_checked_mul {
+    use nordint::{BigUint, NttError, RootOfUnityError};
+    use std::error::Error;
+
+    #[test]
+    fn matches_mul() {
+        let lhs = BigUint::new("123,456,789,987,654,321");
+        let rhs = BigUint::new("192,837,465,564,738,291");
+        assert_eq!(Ok(&lhs * &rhs), lhs.checked_mul(&rhs));
+    }
+
+    #[test]
+    fn zero_product() {
+        let lhs = BigUint::zero();
+        let rhs = BigUint::new("192,837,465,564,738,291");
+        assert_eq!(Ok(BigUint::zero()), lhs.checked_mul(&rhs));
+        assert_eq!(BigUint::zero(), &rhs * &lhs);
+    }
+
+    #[test]
+    fn empty_input() {
+        let lhs = BigUint::empty();
+        let rhs = BigUint::one();
+        assert_eq!(Err(NttError::EmptyInput), lhs.checked_mul(&rhs));
+        assert_eq!(Err(NttError::EmptyInput), rhs.checked_mul(&lhs));
+    }
+
+    #[test]
+    fn too_large_for_one_modulus() {
+        // (10^400000 - 1)^2 = 10^800000 - 2 * 10^400000 + 1 needs two primes.
+        let lhs = BigUint::new(&"9".repeat(400_000));
+        let expected = format!("{}8{}1", "9".repeat(399_999), "0".repeat(399_999));
+        assert_eq!(Ok(BigUint::new(&expected)), lhs.checked_mul(&lhs));
+    }
+
+    #[test]
+    #[should_panic(expected = "[NttError]: cannot transform an empty vector")]
+    fn mul_panics_with_error() {
+        let _ = &BigUint::empty() * &BigUint::one();
+    }
+
+    #[test]
+    fn error_source() {
+        let root_error = RootOfUnityError::OrderDoesNotDivide { order: 5, modulus: 17 };
+        let error = NttError::from(root_error.clone());
+        assert_eq!(NttError::NoPrimitiveRoot(root_error.clone()), error);
+        assert_eq!(
+            root_error.to_string(),
+            error.source().map(|source| source.to_string()).unwrap()
+        );
+        assert!(NttError::EmptyInput.source().is_none());
+        assert_eq!(
+            "transform length `6` is not a power of two",
+            NttError::LengthNotPowerOfTwo(6).to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod biguint_mul_fft_f64 {
+    use common::random_digits;
+    use nordint::BigUint;
+
+    #[test]
+    fn matches_ntt() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for &(lhs_len, rhs_len) in &[(1, 1), (2, 3), (3, 7), (17, 250), (101, 99), (1_000, 1_001), (2_345, 17), (20_001, 19_999)] {
+            let lhs = BigUint::new(&random_digits(&mut state, lhs_len));
+            let rhs = BigUint::new(&random_digits(&mut state, rhs_len));
+            assert_eq!(&lhs * &rhs, lhs.mul_fft_f64(&rhs), "{} x {} digits", lhs_len, rhs_len);
+        }
+    }
+
+    #[test]
+    fn largest_coefficients() {
+        // Every limb is 9999, the worst case for the rounding error.
+        let lhs = BigUint::new(&"9".repeat(100_000));
+        let expected = format!("{}8{}1", "9".repeat(99_999), "0".repeat(99_999));
+        assert!(lhs.fft_f64_error_bound(&lhs) < 0.5);
+        assert_eq!(BigUint::new(&expected), lhs.mul_fft_f64(&lhs));
+    }
+
+    #[test]
+    fn exact_just_below_the_bound() {
+        // All-nines operands close to the largest that still take the floating-point path.
+        let lhs = BigUint::new(&"9".repeat(300_000));
+        let expected = format!("{}8{}1", "9".repeat(299_999), "0".repeat(299_999));
+        let bound = lhs.fft_f64_error_bound(&lhs);
+        assert!(0.4 < bound && bound < 0.5);
+        assert_eq!(BigUint::new(&expected), lhs.mul_fft_f64(&lhs));
+    }
+
+    #[test]
+    fn zero() {
+        let lhs = BigUint::new("123,456,789");
+        assert_eq!(BigUint::zero(), lhs.mul_fft_f64(&BigUint::zero()));
+        assert_eq!(BigUint::zero(), BigUint::zero().mul_fft_f64(&lhs));
+    }
+
+    #[test]
+    fn error_bound_grows_with_length() {
+        let bounds = [10, 1_000, 100_000, 1_000_000]
+            .iter()
+            .map(|len| {
+                let nines = BigUint::new(&"9".repeat(*len));
+                nines.fft_f64_error_bound(&nines)
+            })
+            .collect::<Vec<f64>>();
+        assert!(bounds.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(bounds[2] < 0.5);
+        // Past the bound, `mul_fft_f64` hands the product to the NTT.
+        assert!(bounds[3] > 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "[NttError]: cannot transform an empty vector")]
+    fn empty_panics() {
+        let _ = BigUint::empty().mul_fft_f64(&BigUint::one());
+    }
+}
+
+#[cfg(test)]
+mod biguint_pow {
+    use nordint::BigUint;
+    use num_bigint::BigUint as CrateBigUint;
+    use std::str::FromStr;
+
+    #[test]
+    fn matches_repeated_multiplication() {
+        for &base in &["1", "2", "7", "99", "100", "123456789", "98765432109876543210"] {
+            let crate_base = CrateBigUint::from_str(base).unwrap();
+            let mut expected = CrateBigUint::from(1_u32);
+            for exponent in 0..70 {
+                assert_eq!(expected.to_string(), BigUint::new(base).pow(exponent).to_string(), "{}^{}", base, exponent);
+                expected *= &crate_base;
+            }
+        }
+        assert_eq!(BigUint::one(), BigUint::zero().pow(0));
+        assert_eq!(BigUint::zero(), BigUint::zero().pow(5));
+    }
+
+    #[test]
+    fn large_exponent() {
+        // 2^10_000 has 3011 digits.
+        let power = BigUint::new("2").pow(10_000);
+        assert_eq!(3_011, power.to_string().len());
+        assert!(power.to_string().starts_with("199506311688075838488374216268"));
+        assert!(power.to_string().ends_with("686391511681774304792596709376"));
+    }
+
+    #[test]
+    fn pow_big_matches_pow() {
+        let base = BigUint::new("31415926535");
+        for exponent in &[0_u32, 1, 2, 31, 1_000] {
+            assert_eq!(Some(base.pow(*exponent)), base.pow_big(&BigUint::from(*exponent)));
+        }
+    }
+
+    #[test]
+    fn pow_big_size_guard() {
+        let two = BigUint::new("2");
+        // 2^60_000_000 would have about eighteen million digits.
+        assert_eq!(None, two.pow_big(&BigUint::new("60,000,000")));
+        assert_eq!(None, two.pow_big(&BigUint::new("123456789123456789123456789")));
+        assert_eq!(None, BigUint::new(&"9".repeat(1_000)).pow_big(&BigUint::new("20,000")));
+        assert_eq!(Some(BigUint::zero()), BigUint::zero().pow_big(&BigUint::new("123456789123456789123456789")));
+        assert_eq!(Some(BigUint::one()), BigUint::one().pow_big(&BigUint::new("123456789123456789123456789")));
+        assert_eq!(Some(BigUint::one()), BigUint::zero().pow_big(&BigUint::zero()));
+    }
+
+    #[test]
+    fn pow_of_ten() {
+        for n in 0..200 {
+            let expected = format!("1{}", "0".repeat(n));
+            assert_eq!(BigUint::new(&expected), BigUint::pow_of_ten(n));
+            assert_eq!(BigUint::new("10").pow(n as u32), BigUint::pow_of_ten(n));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot raise an empty BigUint to a power")]
+    fn empty_panics() {
+        let _ = BigUint::empty().pow(2);
+    }
+}
+
+#[cfg(test)]
+mod biguint_roots {
+    use common::random_digits;
+    use nordint::BigUint;
+    use num_bigint::BigUint as CrateBigUint;
+    use std::str::FromStr;
+
+    #[test]
+    fn isqrt_small() {
+        for number in 0..2_000_u32 {
+            let expected = f64::from(number).sqrt().floor() as u32;
+            assert_eq!(BigUint::from(expected), BigUint::from(number).isqrt(), "{}", number);
+        }
+    }
+
+    #[test]
+    fn sqrt_two() {
+        // The first thousand digits of the square root of two.
+        let mut two = BigUint::pow_of_ten(2 * 999);
+        two *= 2;
+        let expected = CrateBigUint::from_str(&two.to_string()).unwrap().sqrt();
+        let actual = two.isqrt();
+        assert_eq!(expected.to_string(), actual.to_string());
+        assert_eq!(1_000, actual.to_string().len());
+        assert!(actual.to_string().starts_with("14142135623730950488016887242096980785696718753769"));
+    }
+
+    #[test]
+    fn sqrt_rem_random() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for &len in &[1, 2, 3, 17, 40, 99, 100, 101, 500, 2_000, 4_001] {
+            let digits = random_digits(&mut state, len);
+            let number = BigUint::new(&digits);
+            let (root, remainder) = number.sqrt_rem();
+            let expected = CrateBigUint::from_str(&digits).unwrap().sqrt();
+            assert_eq!(expected.to_string(), root.to_string());
+            let expected_remainder = CrateBigUint::from_str(&digits).unwrap() - &expected * &expected;
+            assert_eq!(expected_remainder.to_string(), remainder.to_string());
+        }
+    }
+
+    #[test]
+    fn nth_root_random() {
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        for &len in &[1, 5, 30, 100, 1_001, 3_000] {
+            let digits = random_digits(&mut state, len);
+            let number = BigUint::new(&digits);
+            for &k in &[1, 2, 3, 4, 5, 7, 12, 31, 100, 1_000, 5_000] {
+                let expected = CrateBigUint::from_str(&digits).unwrap().nth_root(k);
+                assert_eq!(expected.to_string(), number.nth_root(k).to_string(), "{} digits, k = {}", len, k);
+            }
+        }
+    }
+
+    #[test]
+    fn nth_root_exact_powers() {
+        let base = BigUint::new("123456789123456789");
+        for k in 1..40 {
+            let power = base.pow(k);
+            assert_eq!(base, power.nth_root(k));
+            let below = &power - &BigUint::one();
+            assert_eq!(&base - &BigUint::one(), below.nth_root(k));
+        }
+    }
+
+    #[test]
+    fn perfect_squares() {
+        let mut state = 0x1234_5678_9abc_def1_u64;
+        for &len in &[1, 10, 333, 2_500] {
+            let root = BigUint::new(&random_digits(&mut state, len));
+            let square = &root * &root;
+            assert!(square.is_perfect_square());
+            let mut next = square.clone();
+            next += &BigUint::one();
+            assert!(!next.is_perfect_square());
+        }
+        assert!(BigUint::zero().is_perfect_square());
+        assert!(BigUint::one().is_perfect_square());
+        assert!(!BigUint::new("2").is_perfect_square());
+    }
+
+    #[test]
+    fn perfect_powers() {
+        let perfect = (2..=1_000_u32)
+            .filter(|number| BigUint::from(*number).is_perfect_power())
+            .collect::<Vec<u32>>();
+        let expected = (2..=1_000_u32)
+            .filter(|number| {
+                (2..=10).any(|k| {
+                    let root = (f64::from(*number).powf(1.0 / f64::from(k))).round() as u32;
+                    root.pow(k) == *number
+                })
+            })
+            .collect::<Vec<u32>>();
+        assert_eq!(expected, perfect);
+
+        let base = BigUint::new("987654321987654321");
+        assert!(base.pow(7).is_perfect_power());
+        assert!(base.pow(12).is_perfect_power());
+        let mut near = base.pow(7);
+        near += &BigUint::one();
+        assert!(!near.is_perfect_power());
+        assert!(BigUint::zero().is_perfect_power());
+        assert!(BigUint::one().is_perfect_power());
+    }
+
+    #[test]
+    #[should_panic(expected = "the zeroth root is undefined")]
+    fn zeroth_root() {
+        let _ = BigUint::new("8").nth_root(0);
+    }
+}
+
+#[cfg(test)]
+mod biguint_fibonacci {
+    use nordint::BigUint;
+
+    #[test]
+    fn fib_matches_addition() {
+        let (mut current, mut next) = (BigUint::zero(), BigUint::one());
+        for n in 0..500 {
+            assert_eq!((current.clone(), next.clone()), BigUint::fib_pair(n), "n = {}", n);
+            if 0 < n {
+                assert_eq!(current, BigUint::fib(n));
+            }
+            let mut sum = current.clone();
+            sum += &next;
+            current = next;
+            next = sum;
+        }
+    }
+
+    #[test]
+    fn fib_large() {
+        let fib = BigUint::fib(100_000).to_string();
+        assert_eq!(20_899, fib.len());
+        assert!(fib.starts_with("25974069347221724166"));
+        assert!(fib.ends_with("49895374653428746875"));
+    }
+
+    #[test]
+    fn lucas_matches_addition() {
+        let (mut current, mut next) = (BigUint::new("2"), BigUint::one());
+        for n in 0..300 {
+            assert_eq!(current, BigUint::lucas(n), "n = {}", n);
+            let mut sum = current.clone();
+            sum += &next;
+            current = next;
+            next = sum;
+        }
+    }
+
+    #[test]
+    fn fib_generic_matches_addition() {
+        for &(first, second) in &[(1_u64, 1_u64), (2, 1), (5, 6), (0, 7), (123_456_789, 987_654_321)] {
+            let (mut current, mut next) = (BigUint::from(first), BigUint::from(second));
+            for n in 1..80 {
+                let actual = BigUint::fib_generic(BigUint::from(first), BigUint::from(second), n);
+                assert_eq!(current, actual, "{}, {}, n = {}", first, second, n);
+                let mut sum = current.clone();
+                sum += &next;
+                current = next;
+                next = sum;
+            }
+        }
+    }
+
+    /// Calculates `(U(n), V(n))` modulo `modulus` straight from the recurrence.
+    fn naive_lucas_sequence(p: i64, q: i64, n: usize, modulus: i64) -> (i64, i64) {
+        let step = |previous: i64, current: i64| (p * current - q * previous).rem_euclid(modulus);
+        let (mut u, mut u_next) = (0, 1 % modulus);
+        let (mut v, mut v_next) = (2 % modulus, p.rem_euclid(modulus));
+        for _ in 0..n {
+            let next = step(u, u_next);
+            u = u_next;
+            u_next = next;
+            let next = step(v, v_next);
+            v = v_next;
+            v_next = next;
+        }
+        (u, v)
+    }
+
+    #[test]
+    fn lucas_sequence_matches_recurrence() {
+        for &(p, q) in &[(1, -1), (3, 2), (-4, 7), (5, -3), (0, 1), (2, 1)] {
+            for &modulus in &[1_i64, 10, 97, 65_536, 999_999_937] {
+                for n in 0..40 {
+                    let (u, v) = BigUint::lucas_sequence(p, q, &BigUint::from(n), &BigUint::from(modulus as u64));
+                    let (expected_u, expected_v) = naive_lucas_sequence(p, q, n, modulus);
+                    assert_eq!(BigUint::from(expected_u as u64), u, "U({}) for P = {}, Q = {} mod {}", n, p, q, modulus);
+                    assert_eq!(BigUint::from(expected_v as u64), v, "V({}) for P = {}, Q = {} mod {}", n, p, q, modulus);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lucas_sequence_large_index() {
+        // For a prime p = +-2 mod 5, p + 1 divides the period of the Fibonacci numbers mod p,
+        // so F(p + 1) = 0 mod p.
+        let prime = BigUint::new("1,000,000,000,000,000,000,000,000,000,057");
+        let mut index = prime.clone();
+        index += &BigUint::one();
+        let (u, v) = BigUint::lucas_sequence(1, -1, &index, &prime);
+        assert_eq!(BigUint::zero(), u);
+        assert_eq!(&prime - &BigUint::new("2"), v);
+        let modulus = BigUint::pow_of_ten(20);
+        let (u, _) = BigUint::lucas_sequence(1, -1, &BigUint::from(100_000_u32), &modulus);
+        assert_eq!(BigUint::new("49895374653428746875"), u);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn lucas_sequence_zero_modulus() {
+        let _ = BigUint::lucas_sequence(1, -1, &BigUint::one(), &BigUint::zero());
+    }
+}
+
+#[cfg(test)]
+mod biguint_factorials {
+    use nordint::BigUint;
+    use num_bigint::BigUint as CrateBigUint;
+
+    #[test]
+    fn fac_matches_running_product() {
+        let mut expected = CrateBigUint::from(1_u32);
+        for n in 0..300_u32 {
+            if 0 < n {
+                expected *= n;
+            }
+            assert_eq!(expected.to_string(), BigUint::fac(n).to_string(), "{}!", n);
+        }
+    }
+
+    #[test]
+    fn fac_large() {
+        let mut expected = CrateBigUint::from(1_u32);
+        for n in 1..=5_000_u32 {
+            expected *= n;
+        }
+        assert_eq!(expected.to_string(), BigUint::fac(5_000).to_string());
+
+        let fac = BigUint::fac(30_000).to_string();
+        assert_eq!(121_288, fac.len());
+        assert!(fac.starts_with("275953724621938459937994216642"));
+        let trimmed = fac.trim_end_matches('0');
+        assert_eq!(7_498, fac.len() - trimmed.len());
+        assert!(trimmed.ends_with("863497875672812928068847927296"));
+    }
+
+    #[test]
+    fn double_factorial_matches_naive() {
+        for n in 0..300_u32 {
+            let mut expected = CrateBigUint::from(1_u32);
+            let mut factor = n;
+            while 1 < factor {
+                expected *= factor;
+                factor -= 2;
+            }
+            assert_eq!(expected.to_string(), BigUint::double_factorial(n).to_string(), "{}!!", n);
+        }
+    }
+
+    #[test]
+    fn double_factorials_multiply_to_factorial() {
+        for &n in &[1_000_u32, 4_001] {
+            assert_eq!(BigUint::fac(n), &BigUint::double_factorial(n) * &BigUint::double_factorial(n - 1));
+        }
+    }
+
+    #[test]
+    fn primorial_matches_naive() {
+        let mut expected = CrateBigUint::from(1_u32);
+        for n in 0..600_u32 {
+            if 1 < n && (2..n).take_while(|divisor| divisor * divisor <= n).all(|divisor| 0 != n % divisor) {
+                expected *= n;
+            }
+            assert_eq!(expected.to_string(), BigUint::primorial(n).to_string(), "{}#", n);
+        }
+    }
+
+    #[test]
+    fn falling_and_rising_factorials() {
+        for x in 0..40_u32 {
+            for k in 0..40_u32 {
+                let mut falling = CrateBigUint::from(1_u32);
+                let mut rising = CrateBigUint::from(1_u32);
+                for i in 0..k {
+                    falling *= x.saturating_sub(i);
+                    rising *= x + i;
+                }
+                let number = BigUint::from(x);
+                assert_eq!(falling.to_string(), number.falling_factorial(k).to_string(), "{} falling {}", x, k);
+                assert_eq!(rising.to_string(), number.rising_factorial(k).to_string(), "{} rising {}", x, k);
+            }
+        }
+    }
+
+    #[test]
+    fn falling_and_rising_factorials_of_large_numbers() {
+        let number = BigUint::new("123,456,789,123,456,789,123,456,789");
+        let below = &number - &BigUint::new("99");
+        assert_eq!(number.falling_factorial(100), below.rising_factorial(100));
+        let mut expected = BigUint::one();
+        let mut factor = number.clone();
+        for _ in 0..100 {
+            expected = &expected * &factor;
+            factor = &factor - &BigUint::one();
+        }
+        assert_eq!(expected, number.falling_factorial(100));
+
+        // Crossing u64::MAX switches from packed words to BigUint factors.
+        let near_max = BigUint::from(u64::MAX - 2);
+        let mut expected = BigUint::one();
+        let mut factor = near_max.clone();
+        for _ in 0..5 {
+            expected = &expected * &factor;
+            factor += &BigUint::one();
+        }
+        assert_eq!(expected, near_max.rising_factorial(5));
+    }
+}
+
+#[cfg(test)]
+mod biguint_combinatorics {
+    use nordint::BigUint;
+
+    #[test]
+    fn binomial_matches_pascals_triangle() {
+        let mut row = vec![BigUint::one()];
+        for n in 0..80_u32 {
+            for k in 0..=n {
+                assert_eq!(row[k as usize], BigUint::binomial(n, k), "C({}, {})", n, k);
+            }
+            assert_eq!(BigUint::zero(), BigUint::binomial(n, n + 1));
+            let mut next = vec![BigUint::one()];
+            for pair in row.windows(2) {
+                let mut sum = pair[0].clone();
+                sum += &pair[1];
+                next.push(sum);
+            }
+            next.push(BigUint::one());
+            row = next;
+        }
+    }
+
+    #[test]
+    fn binomial_large() {
+        let binomial = BigUint::binomial(100_000, 50_000).to_string();
+        assert_eq!(30_101, binomial.len());
+        assert!(binomial.starts_with("252060836892200338850090011673"));
+        assert!(binomial.ends_with("640886651313872846009891416640"));
+        assert_eq!(BigUint::new("1,333,331,333,334,000,000"), BigUint::binomial(2_000_000, 3));
+    }
+
+    #[test]
+    fn multinomial_matches_binomials() {
+        let parts = [3_u32, 5, 0, 7, 2];
+        let mut expected = BigUint::one();
+        let mut total = 0;
+        for part in &parts {
+            total += part;
+            expected = &expected * &BigUint::binomial(total, *part);
+        }
+        assert_eq!(expected, BigUint::multinomial(&parts));
+        assert_eq!(BigUint::binomial(30, 12), BigUint::multinomial(&[12, 18]));
+        assert_eq!(BigUint::fac(20), BigUint::multinomial(&[1; 20]));
+    }
+
+    #[test]
+    fn catalan_table() {
+        let table: [u64; 20] = [
+            1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862, 16796, 58786, 208012, 742900, 2674440, 9694845, 35357670,
+            129644790, 477638700, 1767263190,
+        ];
+        for (n, expected) in table.iter().enumerate() {
+            assert_eq!(BigUint::from(*expected), BigUint::catalan(n as u32), "C_{}", n);
+        }
+        let catalan = BigUint::catalan(5_000).to_string();
+        assert_eq!(3_005, catalan.len());
+        assert!(catalan.starts_with("318294393827722245218475759576"));
+        assert!(catalan.ends_with("817911545408733885900308049120"));
+    }
+
+    #[test]
+    fn stirling2_table() {
+        let table: [&[u64]; 8] = [
+            &[1],
+            &[0, 1],
+            &[0, 1, 1],
+            &[0, 1, 3, 1],
+            &[0, 1, 7, 6, 1],
+            &[0, 1, 15, 25, 10, 1],
+            &[0, 1, 31, 90, 65, 15, 1],
+            &[0, 1, 63, 301, 350, 140, 21, 1],
+        ];
+        for (n, row) in table.iter().enumerate() {
+            for (k, expected) in row.iter().enumerate() {
+                assert_eq!(BigUint::from(*expected), BigUint::stirling2(n as u32, k as u32), "S({}, {})", n, k);
+            }
+            assert_eq!(BigUint::zero(), BigUint::stirling2(n as u32, n as u32 + 1));
+        }
+        assert_eq!(
+            BigUint::new("30735091067506348265316492487884186522551191142651551568795842538739907703858915799682249451719895656747741843280"),
+            BigUint::stirling2(100, 37)
+        );
+    }
+
+    #[test]
+    fn bell_table() {
+        let table: [u64; 20] = [
+            1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147, 115975, 678570, 4213597, 27644437, 190899322, 1382958545,
+            10480142147, 82864869804, 682076806159, 5832742205057,
+        ];
+        for (n, expected) in table.iter().enumerate() {
+            assert_eq!(BigUint::from(*expected), BigUint::bell(n as u32), "B_{}", n);
+        }
+        assert_eq!(
+            BigUint::new("47585391276764833658790768841387207826363669686825611466616334637559114497892442622672724044217756306953557882560751"),
+            BigUint::bell(100)
+        );
+        // Bell numbers are the row sums of the Stirling numbers of the second kind.
+        let mut sum = BigUint::zero();
+        for k in 0..=40 {
+            sum += &BigUint::stirling2(40, k);
+        }
+        assert_eq!(sum, BigUint::bell(40));
+    }
+
+    #[test]
+    fn partition_count_table() {
+        let table: [u64; 30] = [
+            1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42, 56, 77, 101, 135, 176, 231, 297, 385, 490, 627, 792, 1002, 1255,
+            1575, 1958, 2436, 3010, 3718, 4565,
+        ];
+        for (n, expected) in table.iter().enumerate() {
+            assert_eq!(BigUint::from(*expected), BigUint::partition_count(n as u32), "p({})", n);
+        }
+        assert_eq!(BigUint::new("24061467864032622473692149727991"), BigUint::partition_count(1_000));
+    }
+}
+
+#[cfg(test)]
+mod biguint_sum_product {
+    use nordint::{product_tree, BigUint};
+
+    #[test]
+    fn sum_owned_and_borrowed() {
+        let numbers: Vec<BigUint> = (1..=1_000_u32).map(BigUint::from).collect();
+        assert_eq!(BigUint::from(500_500_u32), numbers.iter().sum());
+        assert_eq!(BigUint::from(500_500_u32), numbers.into_iter().sum());
+        let squares: BigUint = (0..100_u32).map(|n| BigUint::from(n).pow(20)).sum();
+        let expected: BigUint = (0..100_u32).map(|n| BigUint::from(n).pow(20)).fold(BigUint::zero(), |mut sum, n| {
+            sum += &n;
+            sum
+        });
+        assert_eq!(expected, squares);
+    }
+
+    #[test]
+    fn empty_sum_is_zero() {
+        assert_eq!(BigUint::zero(), Vec::<BigUint>::new().into_iter().sum());
+        assert_eq!(BigUint::new("7"), [BigUint::empty(), BigUint::new("7")].iter().sum());
+    }
+
+    #[test]
+    fn product_owned_and_borrowed() {
+        let factors: Vec<BigUint> = (1..=3_000_u32).map(BigUint::from).collect();
+        assert_eq!(BigUint::fac(3_000), factors.iter().product());
+        assert_eq!(BigUint::fac(3_000), factors.into_iter().product());
+    }
+
+    #[test]
+    fn product_matches_sequential() {
+        let factors: Vec<BigUint> = (0..200_u32).map(|n| BigUint::from(u64::MAX - u64::from(n))).collect();
+        let mut expected = BigUint::one();
+        for factor in &factors {
+            expected = &expected * factor;
+        }
+        assert_eq!(expected, factors.iter().product());
+        assert_eq!(expected, product_tree(&factors));
+    }
+
+    #[test]
+    fn empty_product_is_one() {
+        assert_eq!(BigUint::one(), Vec::<BigUint>::new().into_iter().product());
+        assert_eq!(BigUint::one(), product_tree(&[]));
+        assert_eq!(BigUint::zero(), [BigUint::new("5"), BigUint::zero()].iter().product());
+        assert_eq!(BigUint::zero(), [BigUint::new("5"), BigUint::empty()].iter().product());
+    }
+}
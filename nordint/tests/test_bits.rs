@@ -0,0 +1,142 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+extern crate num_bigint;
+
+mod common;
+
+#[cfg(test)]
+mod bits_queries {
+    use common::random_digits;
+    use nordint::BigUint;
+    use num_bigint::BigUint as CrateBigUint;
+    use std::str::FromStr;
+
+    #[test]
+    fn matches_u64() {
+        for number in (0..2_000_u64).chain((0..64).map(|shift| 1 << shift)).chain(Some(u64::MAX)) {
+            let big = BigUint::from(number);
+            assert_eq!(u64::from(64 - number.leading_zeros()), big.bits(), "{}", number);
+            assert_eq!(u64::from(number.count_ones()), big.count_ones(), "{}", number);
+            let trailing = if 0 == number { None } else { Some(u64::from(number.trailing_zeros())) };
+            assert_eq!(trailing, big.trailing_zeros(), "{}", number);
+            for bit in 0..70 {
+                assert_eq!(bit < 64 && 1 == (number >> bit) & 1, big.bit(bit), "{} bit {}", number, bit);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_crate() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for &len in &[19, 20, 39, 100, 1_000] {
+            let digits = random_digits(&mut state, len);
+            let expected = CrateBigUint::from_str(&digits).unwrap();
+            let binary = expected.to_str_radix(2);
+            let actual = BigUint::new(&digits);
+            let words = expected
+                .to_u32_digits()
+                .chunks(2)
+                .map(|pair| u64::from(pair[0]) | u64::from(pair.get(1).cloned().unwrap_or(0)) << 32)
+                .collect::<Vec<u64>>();
+            assert_eq!(words, actual.to_u64_digits());
+            assert_eq!(binary.len() as u64, actual.bits());
+            assert_eq!(binary.matches('1').count() as u64, actual.count_ones());
+            let trailing = binary.len() - 1 - binary.rfind('1').unwrap();
+            assert_eq!(Some(trailing as u64), actual.trailing_zeros());
+            for (bit, digit) in binary.bytes().rev().enumerate() {
+                assert_eq!(b'1' == digit, actual.bit(bit as u64));
+            }
+            assert!(!actual.bit(binary.len() as u64));
+        }
+    }
+
+    #[test]
+    fn empty_is_zero() {
+        assert_eq!(0, BigUint::empty().bits());
+        assert_eq!(None, BigUint::empty().trailing_zeros());
+        assert!(!BigUint::empty().bit(0));
+    }
+
+    #[test]
+    fn set_and_clear_bits() {
+        let mut number = BigUint::zero();
+        for &bit in &[0, 3, 64, 65, 200] {
+            number.set_bit(bit, true);
+            assert!(number.bit(bit));
+        }
+        let expected = (CrateBigUint::from(1_u32) << 200) + (CrateBigUint::from(3_u32) << 64) + 9_u32;
+        assert_eq!(expected.to_string(), number.to_string());
+        assert_eq!(5, number.count_ones());
+        number.set_bit(200, true);
+        number.set_bit(64, false);
+        number.set_bit(1_000, false);
+        let expected = expected - (CrateBigUint::from(1_u32) << 64);
+        assert_eq!(expected.to_string(), number.to_string());
+    }
+}
+
+#[cfg(test)]
+mod bits_operators {
+    use common::random_digits;
+    use nordint::BigUint;
+    use num_bigint::BigUint as CrateBigUint;
+    use std::str::FromStr;
+
+    fn operands() -> Vec<(String, String)> {
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        [(1, 1), (5, 30), (40, 40), (300, 20), (500, 480)]
+            .iter()
+            .map(|&(lhs_len, rhs_len)| (random_digits(&mut state, lhs_len), random_digits(&mut state, rhs_len)))
+            .collect()
+    }
+
+    #[test]
+    fn and_or_xor() {
+        for (lhs, rhs) in operands() {
+            let (crate_lhs, crate_rhs) = (CrateBigUint::from_str(&lhs).unwrap(), CrateBigUint::from_str(&rhs).unwrap());
+            let (lhs, rhs) = (BigUint::new(&lhs), BigUint::new(&rhs));
+            assert_eq!((&crate_lhs & &crate_rhs).to_string(), (&lhs & &rhs).to_string());
+            assert_eq!((&crate_lhs | &crate_rhs).to_string(), (lhs.clone() | &rhs).to_string());
+            assert_eq!((&crate_lhs ^ &crate_rhs).to_string(), (lhs.clone() ^ rhs.clone()).to_string());
+            let mut number = lhs.clone();
+            number &= rhs.clone();
+            number |= &lhs;
+            assert_eq!(lhs, number);
+            number ^= &lhs;
+            assert_eq!(BigUint::zero(), number);
+        }
+    }
+
+    #[test]
+    fn shifts() {
+        for (lhs, _) in operands() {
+            let expected = CrateBigUint::from_str(&lhs).unwrap();
+            let number = BigUint::new(&lhs);
+            for &shift in &[0, 1, 15, 16, 17, 63, 64, 65, 100, 1_000, 5_000] {
+                assert_eq!((&expected << shift).to_string(), (&number << shift).to_string(), "{} << {}", lhs, shift);
+                assert_eq!((&expected >> shift).to_string(), (&number >> shift).to_string(), "{} >> {}", lhs, shift);
+            }
+            let mut shifted = number.clone() << 300;
+            shifted >>= 300;
+            assert_eq!(number, shifted);
+            shifted <<= 7;
+            assert_eq!(&number * 128_u8, shifted);
+        }
+    }
+
+    #[test]
+    fn not_within_bit_length() {
+        assert_eq!(BigUint::zero(), !BigUint::zero());
+        assert_eq!(BigUint::new("5"), !BigUint::new("10"));
+        assert_eq!(BigUint::zero(), !BigUint::from(u64::MAX));
+        let number = BigUint::new("123,456,789,012,345,678,901,234,567,890");
+        let all_ones = &(BigUint::one() << number.bits() as usize) - &BigUint::one();
+        assert_eq!(&all_ones - &number, !&number);
+        assert_eq!(all_ones, &number | &!&number);
+    }
+}
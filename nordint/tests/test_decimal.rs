@@ -0,0 +1,260 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+#[cfg(test)]
+fn decimal(text: &str) -> nordint::BigDecimal {
+    text.parse().unwrap()
+}
+
+/// Converts through the plain decimal string, which `BigRational` parses exactly.
+#[cfg(test)]
+fn to_rational(value: &nordint::BigDecimal) -> nordint::BigRational {
+    value.to_string().parse().unwrap()
+}
+
+#[cfg(test)]
+mod decimal_arithmetic {
+    use super::{decimal, to_rational};
+    use nordint::{BigDecimal, BigRational};
+
+    /// Small decimals with every combination of signs and a few scales.
+    fn decimals() -> Vec<BigDecimal> {
+        let mut decimals = Vec::new();
+        for &unscaled in &[-1_234, -50, -7, 0, 3, 10, 999] {
+            for &scale in &[-2, 0, 1, 3] {
+                decimals.push(decimal(&format!("{}e{}", unscaled, -scale)));
+            }
+        }
+        decimals
+    }
+
+    #[test]
+    fn matches_rational() {
+        for lhs in &decimals() {
+            for rhs in &decimals() {
+                let (exact_lhs, exact_rhs) = (to_rational(lhs), to_rational(rhs));
+                let sum = lhs + rhs;
+                assert_eq!(&exact_lhs + &exact_rhs, to_rational(&sum), "{} + {}", lhs, rhs);
+                assert_eq!(lhs.scale().max(rhs.scale()), sum.scale());
+                assert_eq!(&exact_lhs - &exact_rhs, to_rational(&(lhs - rhs)), "{} - {}", lhs, rhs);
+                let product = lhs * rhs;
+                assert_eq!(&exact_lhs * &exact_rhs, to_rational(&product), "{} * {}", lhs, rhs);
+                assert_eq!(lhs.scale() + rhs.scale(), product.scale());
+                assert_eq!(exact_lhs.cmp(&exact_rhs), lhs.cmp(rhs), "{} vs {}", lhs, rhs);
+            }
+        }
+    }
+
+    #[test]
+    fn owned_and_assigning_forms() {
+        let mut value = decimal("1.5");
+        value += decimal("0.25");
+        value -= &decimal("2");
+        value *= decimal("-4");
+        assert_eq!(decimal("1"), value);
+        assert_eq!("1.00", value.to_string());
+        assert_eq!(decimal("3.75"), decimal("1.5") + &decimal("2.25"));
+        assert_eq!(decimal("-0.75"), &decimal("1.5") - decimal("2.25"));
+        assert_eq!(decimal("3.375"), decimal("1.5") * decimal("2.25"));
+        assert_eq!(decimal("-1.5"), -decimal("1.5"));
+    }
+
+    #[test]
+    fn equality_ignores_scale() {
+        assert_eq!(decimal("1"), decimal("1.000"));
+        assert_eq!(decimal("1200"), decimal("12e2"));
+        assert_eq!(decimal("0"), decimal("-0.00"));
+        assert!(!decimal("-0.00").is_negative());
+        assert!(decimal("0.1") < decimal("0.10001"));
+        assert!(decimal("-0.1") > decimal("-0.10001"));
+        assert_eq!(BigRational::zero(), to_rational(&(&decimal("-2.5") + &decimal("2.50"))));
+    }
+
+    #[test]
+    fn exact_sum_of_tenths() {
+        let tenth = decimal("0.1");
+        let sum = (0..10).fold(BigDecimal::zero(), |sum, _| sum + &tenth);
+        assert_eq!(BigDecimal::one(), sum);
+        assert_eq!("1.0", sum.to_string());
+    }
+}
+
+#[cfg(test)]
+mod decimal_rounding {
+    use super::{decimal, to_rational};
+    use nordint::{BigDecimal, BigRational, RoundingMode};
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::HalfEven,
+        RoundingMode::HalfUp,
+        RoundingMode::Floor,
+        RoundingMode::Ceiling,
+        RoundingMode::Truncate,
+    ];
+
+    #[test]
+    fn with_scale_modes() {
+        // value, half even, half up, floor, ceiling, truncate, all at one digit.
+        let cases = [
+            ("2.25", "2.2", "2.3", "2.2", "2.3", "2.2"),
+            ("2.35", "2.4", "2.4", "2.3", "2.4", "2.3"),
+            ("-2.25", "-2.2", "-2.3", "-2.3", "-2.2", "-2.2"),
+            ("-2.35", "-2.4", "-2.4", "-2.4", "-2.3", "-2.3"),
+            ("2.251", "2.3", "2.3", "2.2", "2.3", "2.2"),
+            ("-2.249", "-2.2", "-2.2", "-2.3", "-2.2", "-2.2"),
+            ("2.2", "2.2", "2.2", "2.2", "2.2", "2.2"),
+            ("-0.04", "0.0", "0.0", "-0.1", "0.0", "0.0"),
+        ];
+        for &(value, half_even, half_up, floor, ceiling, truncate) in &cases {
+            let expected = [half_even, half_up, floor, ceiling, truncate];
+            for (mode, expected) in MODES.iter().zip(expected.iter()) {
+                let rounded = decimal(value).with_scale(1, *mode);
+                assert_eq!(*expected, rounded.to_string(), "{} with {:?}", value, mode);
+            }
+        }
+        assert_eq!("1250", decimal("1249.9").with_scale(-1, RoundingMode::HalfUp).to_string());
+        assert_eq!("1200", decimal("1250").with_scale(-2, RoundingMode::HalfEven).to_string());
+        assert_eq!(-2, decimal("1250").with_scale(-2, RoundingMode::HalfEven).scale());
+    }
+
+    /// Rounds `value * 10^scale` to an integer with `mode`, using exact fractions.
+    fn expected_unscaled(value: &BigRational, mode: RoundingMode) -> BigRational {
+        let floor = value.floor();
+        let half = "1/2".parse::<BigRational>().unwrap();
+        match mode {
+            RoundingMode::Floor => floor,
+            RoundingMode::Ceiling => value.ceil(),
+            RoundingMode::Truncate => value.trunc(),
+            RoundingMode::HalfUp => value.round(),
+            RoundingMode::HalfEven => {
+                let above = value - &floor;
+                let floor_is_even = (&floor % &BigRational::from(2_u64)).is_zero();
+                if above < half || (above == half && floor_is_even) {
+                    floor
+                } else {
+                    floor + BigRational::one()
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn division_matches_rational() {
+        let values = ["1", "-2", "10", "0.3", "-7.25", "22", "1e3", "-0.0045", "123.456"];
+        for lhs in &values {
+            for rhs in &values {
+                let (lhs, rhs) = (decimal(lhs), decimal(rhs));
+                let exact = &to_rational(&lhs) / &to_rational(&rhs);
+                for scale in -2..6 {
+                    let shift = BigRational::from(10_u64).pow(scale as i32);
+                    for &mode in &MODES {
+                        let quotient = lhs.div_rounded(&rhs, scale, mode);
+                        assert_eq!(scale, quotient.scale());
+                        let expected = &expected_unscaled(&(&exact * &shift), mode) / &shift;
+                        assert_eq!(expected, to_rational(&quotient), "{} / {} at {} with {:?}", lhs, rhs, scale, mode);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_division() {
+        let one = BigDecimal::one();
+        let seven = decimal("7");
+        let sevenths = one.div_rounded(&seven, 60, RoundingMode::HalfEven);
+        assert_eq!(format!("0.{}", "142857".repeat(10)), sevenths.to_string());
+        let sevenths = one.div_rounded(&seven, 61, RoundingMode::HalfEven);
+        assert_eq!(format!("0.{}1", "142857".repeat(10)), sevenths.to_string());
+        let sevenths = one.div_rounded(&seven, 62, RoundingMode::HalfEven);
+        assert_eq!(format!("0.{}14", "142857".repeat(10)), sevenths.to_string());
+        let sevenths = one.div_rounded(&seven, 63, RoundingMode::HalfEven);
+        assert_eq!(format!("0.{}143", "142857".repeat(10)), sevenths.to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide a BigDecimal by zero")]
+    fn divide_by_zero() {
+        let _ = BigDecimal::one().div_rounded(&decimal("0.00"), 2, RoundingMode::HalfEven);
+    }
+}
+
+#[cfg(test)]
+mod decimal_conversions {
+    use super::decimal;
+    use nordint::{BigDecimal, BigUint};
+
+    #[test]
+    fn parse_keeps_every_digit() {
+        let value = decimal("123.4500e-7");
+        assert_eq!(&BigUint::new("1,234,500"), value.unscaled());
+        assert_eq!(11, value.scale());
+        assert_eq!("0.00001234500", value.to_string());
+        let cases = [
+            ("0", "0", 0),
+            ("-0", "0", 0),
+            ("+1.50", "150", 2),
+            ("-.5", "5", 1),
+            ("1E3", "1", -3),
+            ("1.5e+3", "15", -2),
+            ("0.000", "0", 3),
+            ("007.10", "710", 2),
+        ];
+        for &(text, unscaled, scale) in &cases {
+            let value = decimal(text);
+            assert_eq!(unscaled, value.unscaled().to_string(), "{}", text);
+            assert_eq!(scale, value.scale(), "{}", text);
+        }
+        assert!(decimal("-.5").is_negative());
+    }
+
+    #[test]
+    fn display_plain_notation() {
+        let cases = [
+            ("1", 0, "1"),
+            ("1", 3, "0.001"),
+            ("1,234", 2, "12.34"),
+            ("1,234", 4, "0.1234"),
+            ("1,234", 6, "0.001234"),
+            ("1,234", -3, "1234000"),
+            ("0", 2, "0.00"),
+            ("0", -2, "0"),
+        ];
+        for &(unscaled, scale, text) in &cases {
+            assert_eq!(text, BigDecimal::new(BigUint::new(unscaled), scale).to_string());
+        }
+        assert_eq!("-0.05", (-BigDecimal::new(BigUint::new("5"), 2)).to_string());
+        assert_eq!("-42", BigDecimal::from(-42_i64).to_string());
+    }
+
+    #[test]
+    fn normalize_strips_trailing_zeros() {
+        let cases = [
+            ("1.2300", "123", 2),
+            ("1200", "12", -2),
+            ("-100.000", "1", -2),
+            ("0.000", "0", 0),
+            ("5", "5", 0),
+            ("123.4500e-7", "12345", 9),
+        ];
+        for &(text, unscaled, scale) in &cases {
+            let normalized = decimal(text).normalized();
+            assert_eq!(unscaled, normalized.unscaled().to_string(), "{}", text);
+            assert_eq!(scale, normalized.scale(), "{}", text);
+            assert_eq!(decimal(text), normalized);
+        }
+        assert!(decimal("-100.000").normalized().is_negative());
+    }
+
+    #[test]
+    fn parse_errors() {
+        for text in &["", "-", ".", "1.", "1.2.3", "e5", "1e", "1e+", "1e--5", "1e5.5", "1,000", "--1", "a", " 1"] {
+            assert!(text.parse::<BigDecimal>().is_err(), "{:?}", text);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+mod common;
+
+#[cfg(test)]
+mod digits_access {
+    use common::random_digits;
+    use nordint::BigUint;
+
+    #[test]
+    fn matches_string() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for len in 1..60 {
+            let digits = random_digits(&mut state, len);
+            let number = BigUint::new(&digits);
+            let expected: Vec<u8> = digits.bytes().map(|digit| digit - b'0').collect();
+            assert_eq!(len, number.num_digits());
+            assert_eq!(expected, number.digits().collect::<Vec<_>>());
+            assert_eq!(len, number.digits().len());
+            let reversed: Vec<u8> = expected.iter().rev().cloned().collect();
+            assert_eq!(reversed, number.digits().rev().collect::<Vec<_>>());
+            for (index, digit) in reversed.iter().enumerate() {
+                assert_eq!(*digit, number.digit(index));
+            }
+            assert_eq!(0, number.digit(len));
+            let sum: u64 = expected.iter().map(|digit| u64::from(*digit)).sum();
+            assert_eq!(sum, number.digit_sum());
+            assert_eq!(number, BigUint::from_digits(&expected));
+        }
+    }
+
+    #[test]
+    fn both_ends() {
+        let number = BigUint::new("1,234,567");
+        let mut digits = number.digits();
+        assert_eq!(Some(1), digits.next());
+        assert_eq!(Some(7), digits.next_back());
+        assert_eq!(Some(2), digits.next());
+        assert_eq!(Some(6), digits.next_back());
+        assert_eq!(vec![3, 4, 5], digits.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn zero_and_empty() {
+        assert_eq!(1, BigUint::zero().num_digits());
+        assert_eq!(vec![0], BigUint::zero().digits().collect::<Vec<_>>());
+        assert_eq!(0, BigUint::empty().num_digits());
+        assert_eq!(0, BigUint::empty().digits().count());
+        assert_eq!(0, BigUint::empty().digit_sum());
+        assert_eq!(BigUint::zero(), BigUint::from_digits(&[]));
+        assert_eq!(BigUint::zero(), BigUint::from_digits(&[0, 0, 0]));
+    }
+
+    #[test]
+    fn factorial_digit_sum() {
+        assert_eq!(648, BigUint::fac(100).digit_sum());
+        assert_eq!(158, BigUint::fac(100).num_digits());
+    }
+
+    #[test]
+    #[should_panic(expected = "every digit must be less than ten")]
+    fn from_digits_rejects_large_digits() {
+        let _ = BigUint::from_digits(&[1, 10]);
+    }
+}
+
+#[cfg(test)]
+mod digits_shifts {
+    use common::random_digits;
+    use nordint::BigUint;
+
+    #[test]
+    fn shift_left_appends_zeros() {
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        for len in 1..20 {
+            let digits = random_digits(&mut state, len);
+            let number = BigUint::new(&digits);
+            for n in 0..12 {
+                let expected = BigUint::new(&format!("{}{}", digits, "0".repeat(n)));
+                assert_eq!(expected, number.shl_digits(n), "{} << {}", digits, n);
+                assert_eq!(&number * &BigUint::pow_of_ten(n), number.shl_digits(n));
+            }
+        }
+        assert_eq!(BigUint::zero(), BigUint::zero().shl_digits(5));
+        assert_eq!(BigUint::zero(), BigUint::empty().shl_digits(5));
+    }
+
+    #[test]
+    fn shift_right_drops_digits() {
+        let mut state = 0x1234_5678_9abc_def1_u64;
+        for len in 1..20 {
+            let digits = random_digits(&mut state, len);
+            let number = BigUint::new(&digits);
+            for n in 0..25 {
+                let expected = if n < len { BigUint::new(&digits[..len - n]) } else { BigUint::zero() };
+                assert_eq!(expected, number.shr_digits(n), "{} >> {}", digits, n);
+                assert_eq!(number, number.shl_digits(n).shr_digits(n));
+            }
+        }
+    }
+
+    #[test]
+    fn truncate_keeps_lowest_digits() {
+        let mut state = 0x0f0f_1234_abcd_9876_u64;
+        for len in 1..20 {
+            let digits = random_digits(&mut state, len);
+            for n in 0..25 {
+                let mut number = BigUint::new(&digits);
+                number.truncate_digits(n);
+                let expected = if 0 == n {
+                    BigUint::zero()
+                } else if n < len {
+                    BigUint::new(&digits[len - n..])
+                } else {
+                    BigUint::new(&digits)
+                };
+                assert_eq!(expected, number, "{} mod 10^{}", digits, n);
+            }
+        }
+        let mut number = BigUint::new("1,000,000,000,007");
+        number.truncate_digits(5);
+        assert_eq!(BigUint::new("7"), number);
+    }
+
+    #[test]
+    fn last_ten_digits() {
+        // The last ten digits of 1^1 + 2^2 + ... + 1000^1000.
+        let mut sum = BigUint::zero();
+        for n in 1..=1_000_u32 {
+            let mut term = BigUint::from(n).modpow(&BigUint::from(n), &BigUint::pow_of_ten(10));
+            term.truncate_digits(10);
+            sum += &term;
+        }
+        sum.truncate_digits(10);
+        assert_eq!(BigUint::new("9,110,846,700"), sum);
+    }
+}
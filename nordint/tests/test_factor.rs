@@ -0,0 +1,160 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+#[cfg(test)]
+mod factor_u64 {
+    use nordint::{factorize, is_prime};
+
+    fn gcd(lhs: u64, rhs: u64) -> u64 {
+        if 0 == rhs {
+            lhs
+        } else {
+            gcd(rhs, lhs % rhs)
+        }
+    }
+
+    #[test]
+    fn one_has_no_factors() {
+        let factorization = factorize(1);
+        assert!(factorization.factors().is_empty());
+        assert_eq!(1, factorization.value());
+        assert_eq!(1, factorization.totient());
+        assert_eq!(vec![1], factorization.divisors());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_panics() {
+        factorize(0);
+    }
+
+    #[test]
+    fn small_numbers_round_trip() {
+        for number in 1..20_000_u64 {
+            let factorization = factorize(number);
+            assert_eq!(number, factorization.value());
+            assert!(factorization.primes().iter().all(|prime| is_prime(**prime)));
+            assert!(factorization.primes().windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn totient_matches_naive() {
+        for number in 1..500_u64 {
+            let expected = (1..=number).filter(|k| 1 == gcd(*k, number)).count() as u64;
+            assert_eq!(expected, factorize(number).totient(), "{}", number);
+        }
+    }
+
+    #[test]
+    fn divisors_match_naive() {
+        for number in 1..500_u64 {
+            let expected = (1..=number).filter(|k| 0 == number % k).collect::<Vec<u64>>();
+            let factorization = factorize(number);
+            assert_eq!(expected.len() as u64, factorization.divisor_count());
+            assert_eq!(expected, factorization.divisors());
+        }
+    }
+
+    #[test]
+    fn prime_powers() {
+        assert_eq!(&[(2, 63)], factorize(1 << 63).factors());
+        assert_eq!(&[(3, 40)], factorize(3_u64.pow(40)).factors());
+        assert!(factorize(18_446_744_073_709_551_557).is_prime());
+    }
+
+    #[test]
+    fn max_word() {
+        assert_eq!(
+            &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6_700_417, 1)],
+            factorize(u64::MAX).factors()
+        );
+    }
+
+    #[test]
+    fn semiprimes_need_pollard_rho() {
+        assert_eq!(
+            &[(4_294_967_279, 1), (4_294_967_291, 1)],
+            factorize(18_446_743_979_220_271_189).factors()
+        );
+        assert_eq!(
+            &[(1_000_003, 2), (10_000_019, 1)],
+            factorize(10_000_079_000_204_000_171).factors()
+        );
+    }
+}
+
+#[cfg(test)]
+mod factor_biguint {
+    use nordint::BigUint;
+
+    #[test]
+    #[should_panic]
+    fn zero_panics() {
+        BigUint::zero().factorize();
+    }
+
+    #[test]
+    fn small_numbers_agree_with_u64() {
+        for number in 1..300_u64 {
+            let expected = nordint::factorize(number)
+                .factors()
+                .iter()
+                .map(|&(prime, exponent)| (BigUint::from(prime), exponent))
+                .collect::<Vec<_>>();
+            assert_eq!(&expected[..], BigUint::from(number).factorize().factors());
+        }
+    }
+
+    #[test]
+    fn beyond_u64() {
+        let number = BigUint::new("1000004900005700000390001911002223");
+        let factorization = number.factorize();
+        assert_eq!(
+            &[
+                (BigUint::new("1000003"), 1),
+                (BigUint::new("10000019"), 1),
+                (BigUint::new("100000000000000000039"), 1),
+            ],
+            factorization.factors()
+        );
+        assert_eq!(number, factorization.value());
+        assert_eq!(8, factorization.divisor_count());
+    }
+
+    #[test]
+    fn repeated_factors_beyond_u64() {
+        // 2^2 * 3 * 1000003^2 * 10000019 * (10^20 + 39)
+        let number = BigUint::new("12000094800244800209880036972095472080028");
+        let factorization = number.factorize();
+        assert_eq!(
+            &[
+                (BigUint::new("2"), 2),
+                (BigUint::new("3"), 1),
+                (BigUint::new("1000003"), 2),
+                (BigUint::new("10000019"), 1),
+                (BigUint::new("100000000000000000039"), 1),
+            ],
+            factorization.factors()
+        );
+    }
+
+    #[test]
+    fn totient_and_divisors() {
+        let factorization = BigUint::new("1000004900005700000390001911002223").factorize();
+        // (1000003 - 1) * (10000019 - 1) * (10^20 + 39 - 1)
+        assert_eq!(
+            BigUint::new("1000003800003600000380001444001368"),
+            factorization.totient()
+        );
+        let divisors = factorization.divisors();
+        assert_eq!(8, divisors.len());
+        assert_eq!(BigUint::one(), divisors[0]);
+        assert_eq!(BigUint::new("10000049000057"), divisors[3]);
+    }
+}
@@ -0,0 +1,255 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+/// Generates finite `f64`s with random signs and mantissas, and exponents within
+/// `2^-60..2^60`, from a fixed xorshift sequence.
+#[cfg(test)]
+fn random_f64s(state: &mut u64, len: usize) -> Vec<f64> {
+    (0..len)
+        .map(|_| {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            let exponent = 1_023 - 60 + (*state >> 52) % 121;
+            f64::from_bits((*state & (1 << 63)) | exponent << 52 | (*state & ((1 << 52) - 1)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod float_arithmetic {
+    use super::random_f64s;
+    use nordint::{BigFloat, BigUint};
+
+    fn float(value: f64) -> BigFloat {
+        BigFloat::from_f64(value).unwrap()
+    }
+
+    #[test]
+    fn matches_f64_rounding() {
+        // With 53 bits of precision every result must be the correctly rounded f64.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let values = random_f64s(&mut state, 60);
+        for &lhs in &values {
+            for &rhs in &values {
+                let (big_lhs, big_rhs) = (float(lhs), float(rhs));
+                assert_eq!(lhs + rhs, (&big_lhs + &big_rhs).to_f64(), "{} + {}", lhs, rhs);
+                assert_eq!(lhs - rhs, (&big_lhs - &big_rhs).to_f64(), "{} - {}", lhs, rhs);
+                assert_eq!(lhs * rhs, (&big_lhs * &big_rhs).to_f64(), "{} * {}", lhs, rhs);
+                assert_eq!(lhs / rhs, (&big_lhs / &big_rhs).to_f64(), "{} / {}", lhs, rhs);
+                assert_eq!(lhs.partial_cmp(&rhs), big_lhs.partial_cmp(&big_rhs), "{} vs {}", lhs, rhs);
+            }
+            assert_eq!(lhs.abs().sqrt(), float(lhs).abs().sqrt().to_f64(), "sqrt {}", lhs);
+        }
+    }
+
+    #[test]
+    fn ties_round_to_even() {
+        let one = float(1.0);
+        let half_ulp = float(2_f64.powi(-53));
+        assert_eq!(one, &one + &half_ulp);
+        let above_one = float(1.0 + 2_f64.powi(-52));
+        assert_eq!(float(1.0 + 2_f64.powi(-51)), &above_one + &half_ulp);
+        assert_eq!(BigUint::new("2"), *BigFloat::new(BigUint::new("9"), 0, 2).mantissa());
+        assert_eq!(BigUint::new("3"), *BigFloat::new(BigUint::new("11"), 0, 2).mantissa());
+        assert_eq!(BigUint::new("2"), *BigFloat::new(BigUint::new("7"), 0, 2).mantissa());
+        assert_eq!(BigFloat::from(BigUint::new("8")), BigFloat::new(BigUint::new("7"), 0, 2));
+    }
+
+    #[test]
+    fn far_apart_operands() {
+        let one = float(1.0);
+        let tiny = BigFloat::new(BigUint::one(), -10_000, 53);
+        assert_eq!(one, &one + &tiny);
+        assert_eq!(one, &one - &tiny);
+        assert_eq!(-&one, &tiny - &one);
+        // Below one the spacing halves, so `1 - 2^-54` is a tie and `1 - 2^-53` is exact.
+        assert_eq!(one, &one - &BigFloat::new(BigUint::one(), -54, 53));
+        let below = &one - &BigFloat::new(BigUint::one(), -53, 53);
+        assert_eq!(float(1.0 - 2_f64.powi(-53)), below);
+        assert_eq!(below, &below - &tiny);
+        assert_eq!(below, &below + &tiny);
+        let wide = one.with_precision(20_000);
+        assert_eq!(tiny, &(&wide + &tiny) - &wide);
+    }
+
+    #[test]
+    fn exact_cancellation() {
+        let value = float(-123.456);
+        let zero = &value + &-&value;
+        assert!(zero.is_zero());
+        assert!(!zero.is_negative());
+        assert_eq!(BigFloat::zero(53), zero);
+        assert_eq!(BigFloat::zero(53), &value * &BigFloat::zero(10));
+    }
+
+    #[test]
+    fn owned_and_assigning_forms() {
+        let mut value = float(1.5);
+        value += float(0.25);
+        value -= &float(2.0);
+        value *= float(-4.0);
+        value /= &float(0.5);
+        assert_eq!(float(2.0), value);
+        assert_eq!(float(3.5), float(1.5) + float(2.0));
+        assert_eq!(float(-0.5), &float(1.5) - float(2.0));
+        assert_eq!(float(3.0), float(1.5) * &float(2.0));
+        assert_eq!(float(0.75), float(1.5) / float(2.0));
+    }
+
+    #[test]
+    fn precision_of_results() {
+        let low = BigFloat::from(BigUint::new("3")).with_precision(10);
+        let high = BigFloat::from(BigUint::new("7")).with_precision(300);
+        assert_eq!(300, (&low / &high).precision());
+        assert_eq!(300, (&high - &low).precision());
+        assert_eq!(10, low.sqrt().precision());
+        assert_eq!(64, BigFloat::from(-5_i64).precision());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide a BigFloat by zero")]
+    fn divide_by_zero() {
+        let _ = float(1.0) / BigFloat::zero(53);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take the square root of a negative BigFloat")]
+    fn negative_sqrt() {
+        let _ = float(-1.0).sqrt();
+    }
+}
+
+#[cfg(test)]
+mod float_constants {
+    use nordint::{BigFloat, BigUint};
+
+    const SQRT_2: &str = "1.41421356237309504880168872420969807856967187537694807317667973799073247846210703885038753432764157273501384623091229702492483605585073721264412149709993583141322266592750559275579995050115278206057147010955997160597027453459686201472851741864088919860955232923048430871432145083976260362799525140799";
+    const E: &str = "2.71828182845904523536028747135266249775724709369995957496696762772407663035354759457138217852516642742746639193200305992181741359662904357290033429526059563073813232862794349076323382988075319525101901157383418793070215408914993488416750924476146066808226480016847741185374234544243710753907774499207";
+    const PI: &str = "3.14159265358979323846264338327950288419716939937510582097494459230781640628620899862803482534211706798214808651328230664709384460955058223172535940812848111745028410270193852110555964462294895493038196442881097566593344612847564823378678316527120190914564856692346034861045432664821339360726024914127";
+
+    fn integer(value: u64, precision: u64) -> BigFloat {
+        BigFloat::from(value).with_precision(precision)
+    }
+
+    #[test]
+    fn square_root_of_two() {
+        // Correctly rounded to 1000 bits, and then to the 301 digits those bits determine.
+        let root = integer(2, 1_000).sqrt();
+        assert_eq!(SQRT_2, root.to_string());
+        let squared = &root * &root;
+        assert!((&squared - &integer(2, 1_000)).abs() < BigFloat::new(BigUint::one(), -995, 1_000));
+    }
+
+    #[test]
+    fn euler_number() {
+        // e = 1 + 1/1! + 1/2! + ..., summed with a few guard bits.
+        let precision = 1_010;
+        let mut sum = integer(1, precision);
+        let mut term = integer(1, precision);
+        for n in 1..200 {
+            term = &term / &integer(n, precision);
+            sum += &term;
+        }
+        assert_eq!(&E[..300], &sum.with_precision(1_000).to_string()[..300]);
+    }
+
+    /// Sums the series `arctan(1/x) = 1/x - 1/3x^3 + 1/5x^5 - ...`.
+    fn arctan_recip(x: u64, precision: u64) -> BigFloat {
+        let square = integer(x * x, precision);
+        let mut power = &integer(1, precision) / &integer(x, precision);
+        let mut sum = power.clone();
+        for k in 1..400 {
+            power = &power / &square;
+            let term = &power / &integer(2 * k + 1, precision);
+            if 1 == k % 2 {
+                sum -= term;
+            } else {
+                sum += term;
+            }
+        }
+        sum
+    }
+
+    #[test]
+    fn machin_pi() {
+        // pi = 16 arctan(1/5) - 4 arctan(1/239)
+        let precision = 1_010;
+        let pi = &(&integer(16, precision) * &arctan_recip(5, precision))
+            - &(&integer(4, precision) * &arctan_recip(239, precision));
+        assert_eq!(&PI[..300], &pi.with_precision(1_000).to_string()[..300]);
+    }
+}
+
+#[cfg(test)]
+mod float_conversions {
+    use nordint::{BigDecimal, BigFloat, BigUint};
+
+    #[test]
+    fn f64_round_trip() {
+        let values = [0.0, 1.0, -0.5, 0.1, -1.0 / 3.0, 1e300, -2.5e-300, f64::MAX, f64::MIN_POSITIVE, 5e-324];
+        for &value in &values {
+            let float = BigFloat::from_f64(value).unwrap();
+            assert_eq!(value, float.to_f64(), "{}", value);
+        }
+        assert!(BigFloat::from_f64(f64::NAN).is_none());
+        let huge = BigFloat::new(BigUint::one(), 5_000, 53);
+        assert_eq!(f64::INFINITY, huge.to_f64());
+        assert_eq!(f64::NEG_INFINITY, (-huge).to_f64());
+        assert_eq!(0.0, BigFloat::new(BigUint::one(), -5_000, 53).to_f64());
+    }
+
+    #[test]
+    fn subnormals_round_once() {
+        let tiny = 5e-324;
+        // Exact ties between multiples of 2^-1074 go to the even one.
+        assert_eq!(0.0, BigFloat::new(BigUint::one(), -1_075, 53).to_f64());
+        assert_eq!(2.0 * tiny, BigFloat::new(BigUint::new("3"), -1_075, 53).to_f64());
+        assert_eq!(4.0 * tiny, BigFloat::new(BigUint::new("14"), -1_076, 53).to_f64());
+        // 2^-1075 + 2^-1135 is just above the tie. Rounding to 53 bits first would
+        // land on 2^-1075 exactly, and the second rounding would then give zero.
+        let above_tie = BigFloat::new((BigUint::one() << 60) + 1_u8, -1_135, 64);
+        assert_eq!(tiny, above_tie.to_f64());
+        let below_tie = BigFloat::new((BigUint::one() << 60) - 1_u8, -1_135, 64);
+        assert_eq!(0.0, below_tie.to_f64());
+        // The largest subnormal plus half a unit, and a hair more, round up into the normal range.
+        let halfway = BigFloat::new((BigUint::one() << 53) - 1_u8, -1_075, 53);
+        assert_eq!(f64::MIN_POSITIVE, halfway.to_f64());
+        assert_eq!(-f64::MIN_POSITIVE, (-halfway).to_f64());
+    }
+
+    #[test]
+    fn exact_decimal_expansion() {
+        let cases = [
+            (0.5, "0.5"),
+            (-0.375, "-0.375"),
+            (1024.0, "1024"),
+            (1e22, "10000000000000000000000"),
+            (2_f64.powi(-10), "0.0009765625"),
+            (0.0, "0"),
+        ];
+        for &(value, decimal) in &cases {
+            let expected: BigDecimal = decimal.parse().unwrap();
+            let actual = BigFloat::from_f64(value).unwrap().to_decimal();
+            assert_eq!(expected, actual);
+            assert_eq!(decimal, actual.to_string());
+        }
+    }
+
+    #[test]
+    fn significant_digits() {
+        let value = BigFloat::from_f64(2.0 / 3.0).unwrap();
+        assert_eq!("0.6667", value.to_decimal_string(4));
+        assert_eq!("0.666666666666667", value.to_string());
+        assert_eq!("1", BigFloat::from_f64(0.96).unwrap().to_decimal_string(1));
+        assert_eq!("0.1", BigFloat::from_f64(0.1).unwrap().to_string());
+        assert_eq!("-1e21".parse::<BigDecimal>().unwrap().to_string(), BigFloat::from_f64(-1e21).unwrap().to_string());
+        assert_eq!("0.0000001", BigFloat::from_f64(1e-7).unwrap().to_string());
+        assert_eq!("0.2", BigFloat::from_f64(0.25).unwrap().to_decimal_string(0));
+    }
+}
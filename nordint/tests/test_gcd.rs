@@ -0,0 +1,200 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+mod common;
+
+#[cfg(test)]
+mod gcd_lcm {
+    use common::random_digits;
+    use nordint::BigUint;
+
+    fn gcd_u64(lhs: u64, rhs: u64) -> u64 {
+        if 0 == rhs {
+            lhs
+        } else {
+            gcd_u64(rhs, lhs % rhs)
+        }
+    }
+
+    #[test]
+    fn matches_u64() {
+        for lhs in 0..120_u64 {
+            for rhs in 0..120_u64 {
+                let expected = BigUint::from(gcd_u64(lhs, rhs));
+                assert_eq!(expected, BigUint::from(lhs).gcd(&BigUint::from(rhs)), "gcd({}, {})", lhs, rhs);
+            }
+        }
+    }
+
+    #[test]
+    fn large_common_factors() {
+        // Long enough operands go through Lehmer's algorithm.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for &(common_len, cofactor_len) in &[(1, 40), (20, 20), (35, 300), (300, 35), (500, 1_000)] {
+            let common = BigUint::new(&random_digits(&mut state, common_len));
+            let lhs_cofactor = BigUint::new(&random_digits(&mut state, cofactor_len));
+            let rhs_cofactor = BigUint::new(&random_digits(&mut state, cofactor_len + 7));
+            let cofactor_gcd = lhs_cofactor.gcd(&rhs_cofactor);
+            let lhs = &common * &lhs_cofactor;
+            let rhs = &common * &rhs_cofactor;
+            let gcd = lhs.gcd(&rhs);
+            assert_eq!(&common * &cofactor_gcd, gcd);
+            assert_eq!(gcd, rhs.gcd(&lhs));
+            assert_eq!(gcd, *lhs.extended_gcd(&rhs).gcd());
+        }
+    }
+
+    #[test]
+    fn consecutive_fibonacci_numbers() {
+        // The worst case for Euclid's algorithm: every quotient is one.
+        let (lhs, rhs) = (BigUint::fib(2_001), BigUint::fib(2_000));
+        assert_eq!(BigUint::one(), lhs.gcd(&rhs));
+        assert_eq!(BigUint::fib(25), BigUint::fib(2_000).gcd(&BigUint::fib(1_975)));
+    }
+
+    #[test]
+    fn powers_of_two() {
+        let lhs = BigUint::new("2").pow(500);
+        let rhs = &BigUint::new("2").pow(321) * &BigUint::new("3").pow(200);
+        assert_eq!(BigUint::new("2").pow(321), lhs.gcd(&rhs));
+    }
+
+    #[test]
+    fn zero_and_empty() {
+        let number = BigUint::new("123456789123456789123456789123456789");
+        assert_eq!(number, number.gcd(&BigUint::zero()));
+        assert_eq!(number, BigUint::zero().gcd(&number));
+        assert_eq!(number, BigUint::empty().gcd(&number));
+        assert_eq!(BigUint::zero(), BigUint::zero().gcd(&BigUint::zero()));
+    }
+
+    #[test]
+    fn lcm_times_gcd_is_product() {
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        for &len in &[1, 3, 30, 250] {
+            let lhs = &BigUint::new(&random_digits(&mut state, len)) * &BigUint::new("720");
+            let rhs = &BigUint::new(&random_digits(&mut state, len)) * &BigUint::new("1050");
+            assert_eq!(&lhs * &rhs, &lhs.lcm(&rhs) * &lhs.gcd(&rhs));
+        }
+        assert_eq!(BigUint::zero(), BigUint::zero().lcm(&BigUint::new("5")));
+        assert_eq!(BigUint::new("5"), BigUint::one().lcm(&BigUint::new("5")));
+    }
+}
+
+#[cfg(test)]
+mod gcd_extended {
+    use common::random_digits;
+    use nordint::BigUint;
+
+    /// Checks that `lhs * x + rhs * y == gcd`, keeping both sides non-negative.
+    fn check_bezout(lhs: &BigUint, rhs: &BigUint) {
+        let bezout = lhs.extended_gcd(rhs);
+        let (x_negative, x) = bezout.x();
+        let (y_negative, y) = bezout.y();
+        assert!(!(x_negative && y_negative));
+        let lhs_term = lhs * x;
+        let rhs_term = rhs * y;
+        let mut sum = if x_negative { rhs_term.clone() } else { lhs_term.clone() };
+        let mut other = bezout.gcd().clone();
+        if x_negative {
+            other += &lhs_term;
+        } else if y_negative {
+            other += &rhs_term;
+        } else {
+            sum += &rhs_term;
+        }
+        assert_eq!(sum, other, "gcd({}, {})", lhs, rhs);
+        assert_eq!(lhs.gcd(rhs), *bezout.gcd());
+    }
+
+    #[test]
+    fn small_pairs() {
+        for lhs in 0..60_u32 {
+            for rhs in 0..60_u32 {
+                check_bezout(&BigUint::from(lhs), &BigUint::from(rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn random_pairs() {
+        let mut state = 0x1234_5678_9abc_def1_u64;
+        for &(lhs_len, rhs_len) in &[(5, 3), (20, 20), (40, 90), (301, 299), (1_000, 10)] {
+            let common = BigUint::new(&random_digits(&mut state, 4));
+            let lhs = &common * &BigUint::new(&random_digits(&mut state, lhs_len));
+            let rhs = &common * &BigUint::new(&random_digits(&mut state, rhs_len));
+            check_bezout(&lhs, &rhs);
+            check_bezout(&rhs, &lhs);
+        }
+    }
+
+    #[test]
+    fn coefficients_are_minimal() {
+        let lhs = BigUint::new("240");
+        let rhs = BigUint::new("46");
+        let bezout = lhs.extended_gcd(&rhs);
+        assert_eq!(&BigUint::new("2"), bezout.gcd());
+        assert_eq!((true, &BigUint::new("9")), bezout.x());
+        assert_eq!((false, &BigUint::new("47")), bezout.y());
+    }
+
+    #[test]
+    fn zero_operands() {
+        let number = BigUint::new("42");
+        let bezout = number.extended_gcd(&BigUint::zero());
+        assert_eq!((&number, (false, &BigUint::one()), (false, &BigUint::zero())), (bezout.gcd(), bezout.x(), bezout.y()));
+        let bezout = BigUint::zero().extended_gcd(&number);
+        assert_eq!((&number, (false, &BigUint::zero()), (false, &BigUint::one())), (bezout.gcd(), bezout.x(), bezout.y()));
+    }
+}
+
+#[cfg(test)]
+mod gcd_mod_inverse {
+    use common::random_digits;
+    use nordint::BigUint;
+
+    #[test]
+    fn small_moduli() {
+        for modulus in 1..80_u32 {
+            for value in 0..100_u32 {
+                let inverse = BigUint::from(value).mod_inverse(&BigUint::from(modulus));
+                let expected = (0..modulus).find(|candidate| 1 % modulus == value * candidate % modulus);
+                assert_eq!(expected.map(BigUint::from), inverse, "{}^-1 mod {}", value, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn large_moduli() {
+        let mut state = 0x0f0f_1234_abcd_9876_u64;
+        for &len in &[20, 100, 400] {
+            let modulus = BigUint::new(&(random_digits(&mut state, len) + "0"));
+            let value = BigUint::new(&(random_digits(&mut state, len + 3) + "3"));
+            match value.mod_inverse(&modulus) {
+                Some(inverse) => {
+                    assert!(inverse < modulus);
+                    assert_eq!(BigUint::one(), (&value * &inverse).modpow(&BigUint::one(), &modulus));
+                }
+                None => assert_ne!(BigUint::one(), value.gcd(&modulus)),
+            }
+        }
+    }
+
+    #[test]
+    fn not_coprime() {
+        assert_eq!(None, BigUint::new("10").mod_inverse(&BigUint::new("1,000")));
+        assert_eq!(None, BigUint::zero().mod_inverse(&BigUint::new("7")));
+        assert_eq!(Some(BigUint::zero()), BigUint::new("10").mod_inverse(&BigUint::one()));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn zero_modulus() {
+        let _ = BigUint::new("3").mod_inverse(&BigUint::zero());
+    }
+}
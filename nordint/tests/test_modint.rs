@@ -0,0 +1,139 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+#[cfg(test)]
+mod modint_const {
+    use nordint::ModInt;
+
+    type Small = ModInt<13>;
+    type Composite = ModInt<12>;
+    /// The Mersenne prime `2^61 - 1`, whose products need 128 bits.
+    type Large = ModInt<2_305_843_009_213_693_951>;
+
+    #[test]
+    fn matches_i128() {
+        let values = [-30, -13, -1, 0, 1, 2, 7, 12, 13, 25, 1_000];
+        for &lhs in &values {
+            for &rhs in &values {
+                let expected = |value: i128| Small::new((value.rem_euclid(13)) as i64);
+                let (a, b) = (Small::new(lhs), Small::new(rhs));
+                assert_eq!(expected(i128::from(lhs) + i128::from(rhs)), a + b);
+                assert_eq!(expected(i128::from(lhs) - i128::from(rhs)), a - b);
+                assert_eq!(expected(i128::from(lhs) * i128::from(rhs)), a * b);
+                assert_eq!(expected(-i128::from(lhs)), -a);
+                if 0 != b.value() {
+                    assert_eq!(a, a / b * b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_modulus() {
+        let modulus = 2_305_843_009_213_693_951_u128;
+        let (lhs, rhs) = (1_234_567_890_123_456_789_u64, 2_000_000_000_000_000_011_u64);
+        let (a, b) = (Large::from(lhs), Large::from(rhs));
+        assert_eq!((u128::from(lhs) * u128::from(rhs) % modulus) as u64, (a * b).value());
+        assert_eq!((u128::from(lhs) + u128::from(rhs)) as u64 % modulus as u64, (a + b).value());
+        assert_eq!(Large::one(), a.pow(modulus as u64 - 1));
+        assert_eq!(Large::one(), a * a.inv().unwrap());
+        assert_eq!(Large::new(-1), Large::from(modulus as u64 - 1));
+        assert_eq!(Large::zero(), Large::from(u64::MAX) - Large::from(u64::MAX % modulus as u64));
+    }
+
+    #[test]
+    fn inverses_need_coprime_residues() {
+        for value in 0..12 {
+            let residue = Composite::new(value);
+            match residue.inv() {
+                Some(inverse) => assert_eq!(Composite::one(), residue * inverse, "{}", value),
+                None => assert!([0, 2, 3, 4, 6, 8, 9, 10].contains(&value), "{}", value),
+            }
+        }
+        assert_eq!(Some(ModInt::<1>::zero()), ModInt::<1>::new(5).inv());
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(Small::one(), Small::new(0).pow(0));
+        assert_eq!(Small::new(8), Small::new(2).pow(3));
+        assert_eq!(Small::new(2).pow(12), Small::one());
+        assert_eq!(Small::new(3).pow(1_000_000_007), Small::new(3).pow(1_000_000_007 % 12));
+    }
+
+    #[test]
+    fn assigning_forms_and_display() {
+        let mut value = Small::new(5);
+        value += Small::new(10);
+        value -= Small::new(4);
+        value *= Small::new(3);
+        value /= Small::new(2);
+        assert_eq!(Small::new(10), value);
+        assert_eq!("10", value.to_string());
+        assert_eq!(13, value.modulus());
+        assert_eq!(Small::zero(), Small::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by a residue with no inverse")]
+    fn divide_by_zero_divisor() {
+        let _ = Composite::new(1) / Composite::new(6);
+    }
+}
+
+#[cfg(test)]
+mod modint_dynamic {
+    use nordint::{DynModInt, ModInt};
+
+    #[test]
+    fn agrees_with_const_modulus() {
+        const MODULUS: u64 = 998_244_353;
+        let values = [-998_244_354, -5, 0, 3, 65_536, 998_244_352, 123_456_789_012];
+        for &lhs in &values {
+            for &rhs in &values {
+                let (a, b) = (DynModInt::new(lhs, MODULUS), DynModInt::new(rhs, MODULUS));
+                let (c, d) = (ModInt::<MODULUS>::new(lhs), ModInt::<MODULUS>::new(rhs));
+                assert_eq!((c + d).value(), (a + b).value());
+                assert_eq!((c - d).value(), (a - b).value());
+                assert_eq!((c * d).value(), (a * b).value());
+                assert_eq!((-c).value(), (-a).value());
+                if 0 != d.value() {
+                    assert_eq!((c / d).value(), (a / b).value());
+                }
+            }
+            assert_eq!(ModInt::<MODULUS>::new(lhs).pow(12_345).value(), DynModInt::new(lhs, MODULUS).pow(12_345).value());
+        }
+    }
+
+    #[test]
+    fn assigning_forms_and_display() {
+        let modulus = 17;
+        let mut value = DynModInt::new(3, modulus);
+        value += DynModInt::new(20, modulus);
+        value *= DynModInt::new(2, modulus);
+        value -= DynModInt::new(1, modulus);
+        value /= DynModInt::new(3, modulus);
+        assert_eq!(DynModInt::new(15, modulus), value);
+        assert_eq!("15", value.to_string());
+        assert_eq!(modulus, value.modulus());
+        assert_eq!(None, DynModInt::new(34, modulus).inv());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "residues must share a modulus")]
+    fn mixed_moduli() {
+        let _ = DynModInt::new(1, 7) + DynModInt::new(1, 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn zero_modulus() {
+        let _ = DynModInt::new(1, 0);
+    }
+}
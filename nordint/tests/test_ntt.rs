@@ -0,0 +1,593 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+/// A fixed xorshift sequence keeps the random inputs reproducible.
+#[cfg(test)]
+fn random_elements(state: &mut u64, len: usize, modulus: i64) -> Vec<i64> {
+    (0..len)
+        .map(|_| {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            (*state % modulus as u64) as i64
+        })
+        .collect()
+}
+
+/// The O(n^2) definition of the transform: X_k = sum of x_j * omega^(jk).
+#[cfg(test)]
+fn naive_dft(elements: &[i64], omega: i64, modulus: i64) -> Vec<i64> {
+    let n = elements.len();
+    (0..n)
+        .map(|k| {
+            let step = (0..k).fold(1, |power, _| power * omega % modulus);
+            let mut twiddle = 1;
+            let mut sum = 0;
+            for element in elements {
+                sum = (sum + element * twiddle) % modulus;
+                twiddle = twiddle * step % modulus;
+            }
+            sum
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod ntt_kernels {
+    use super::{naive_dft, random_elements};
+    use nordint::{ntt, root_of_unity};
+
+    const MODULUS: i64 = 998_244_353;
+
+    fn omega(n: usize, modulus: i64) -> i64 {
+        root_of_unity(n as u64, modulus as u64).unwrap() as i64
+    }
+
+    #[test]
+    fn cooley_tukey_matches_naive() {
+        let mut state = 0x9e37_79b9_7f4a_7c15;
+        for log in 0..10 {
+            let n = 1 << log;
+            let original = random_elements(&mut state, n, MODULUS);
+            let mut elements = original.clone();
+            ntt::cooley_tukey(&mut elements, omega(n, MODULUS), MODULUS).unwrap();
+            assert_eq!(naive_dft(&original, omega(n, MODULUS), MODULUS), elements);
+        }
+    }
+
+    #[test]
+    fn mixed_radix_matches_naive() {
+        // 2^5 * 3^3 * 5^2 + 1 is prime, so every length below has a root.
+        let modulus = 21_601;
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        for &n in &[1, 2, 3, 5, 6, 9, 10, 12, 15, 18, 25, 27, 30, 45, 50, 60, 75, 90, 150, 240, 270] {
+            let original = random_elements(&mut state, n, modulus);
+            let mut elements = original.clone();
+            ntt::mixed_radix(&mut elements, omega(n, modulus), modulus).unwrap();
+            assert_eq!(naive_dft(&original, omega(n, modulus), modulus), elements, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn bluestein_matches_naive() {
+        // 998244352 = 2^23 * 7 * 17
+        let mut state = 0xdead_beef_cafe_f00d;
+        for &n in &[1, 2, 4, 7, 14, 17, 28, 34, 56, 119, 238] {
+            let original = random_elements(&mut state, n, MODULUS);
+            let mut elements = original.clone();
+            ntt::bluestein(&mut elements, omega(n, MODULUS), MODULUS).unwrap();
+            assert_eq!(naive_dft(&original, omega(n, MODULUS), MODULUS), elements, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn forward_inverse_round_trip() {
+        let modulus = 21_601;
+        let mut state = 0x0123_4567_89ab_cdef;
+        for n in (1..=100).filter(|n| (modulus as usize - 1).is_multiple_of(*n)) {
+            let original = random_elements(&mut state, n, modulus);
+            let mut elements = original.clone();
+            ntt::forward(&mut elements, omega(n, modulus), modulus).unwrap();
+            ntt::inverse(&mut elements, omega(n, modulus), modulus).unwrap();
+            assert_eq!(original, elements, "n = {}", n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ntt_plans {
+    use super::random_elements;
+    use nordint::ntt::{self, Kernel, Plan};
+
+    const MODULUS: i64 = 998_244_353;
+
+    #[test]
+    fn power_of_two_kernels_match_radix_2() {
+        let mut state = 0x8bad_f00d_1bad_b002;
+        for log in 0..=20 {
+            let n = 1 << log;
+            let radix_2 = Plan::new(n, MODULUS).unwrap().with_kernel(Kernel::Radix2).unwrap();
+            let original = random_elements(&mut state, n, MODULUS);
+            let mut expected = original.clone();
+            radix_2.forward(&mut expected).unwrap();
+            for &kernel in &[Kernel::Radix4, Kernel::SplitRadix] {
+                let plan = radix_2.clone().with_kernel(kernel).unwrap();
+                let mut elements = original.clone();
+                plan.forward(&mut elements).unwrap();
+                assert!(expected == elements, "{:?} differs at n = {}", kernel, n);
+                plan.inverse(&mut elements).unwrap();
+                assert!(original == elements, "{:?} round trip differs at n = {}", kernel, n);
+            }
+        }
+    }
+
+    #[test]
+    fn every_kernel_round_trips() {
+        let mut state = 0x0ddb_a11c_0ffe_e000;
+        let kernels = [
+            Kernel::Radix2,
+            Kernel::Radix4,
+            Kernel::SplitRadix,
+            Kernel::MixedRadix,
+            Kernel::Bluestein,
+        ];
+        for &n in &[1, 2, 4, 32, 256] {
+            for &kernel in kernels.iter() {
+                let plan = Plan::new(n, MODULUS).unwrap().with_kernel(kernel).unwrap();
+                let original = random_elements(&mut state, n, MODULUS);
+                let mut elements = original.clone();
+                plan.forward(&mut elements).unwrap();
+                let mut free = original.clone();
+                ntt::cooley_tukey(&mut free, plan.omega(), MODULUS).unwrap();
+                assert_eq!(free, elements, "{:?} at n = {}", kernel, n);
+                plan.inverse(&mut elements).unwrap();
+                assert_eq!(original, elements, "{:?} at n = {}", kernel, n);
+            }
+        }
+    }
+
+    #[test]
+    fn every_kernel_matches_a_known_convolution_when_parallel() {
+        // 2^17 elements is above the length where the `parallel` feature starts
+        // spawning threads, so this compares the threaded kernels with a result
+        // known in closed form: ones(L) * ones(L) = 1, 2, ..., L, ..., 2, 1.
+        let (half, n) = (1 << 16, 1 << 17);
+        let expected = (0..n)
+            .map(|k| if k < half { k as i64 + 1 } else { (n - 1 - k) as i64 })
+            .collect::<Vec<i64>>();
+        let kernels = [
+            Kernel::Radix2,
+            Kernel::Radix4,
+            Kernel::SplitRadix,
+            Kernel::MixedRadix,
+            Kernel::Bluestein,
+        ];
+        for &kernel in kernels.iter() {
+            let plan = Plan::new(n, MODULUS).unwrap().with_kernel(kernel).unwrap();
+            let mut ones = vec![1; half];
+            ones.resize(n, 0);
+            plan.forward(&mut ones).unwrap();
+            let mut convolution = ones.iter().map(|x| x * x % MODULUS).collect::<Vec<i64>>();
+            plan.inverse(&mut convolution).unwrap();
+            assert!(expected == convolution, "{:?} differs from the known convolution", kernel);
+        }
+    }
+
+    #[test]
+    fn default_kernels() {
+        assert_eq!(Kernel::SplitRadix, Plan::new(64, MODULUS).unwrap().kernel());
+        assert_eq!(Kernel::MixedRadix, Plan::new(20, 21_601).unwrap().kernel());
+        assert_eq!(Kernel::Bluestein, Plan::new(17, MODULUS).unwrap().kernel());
+    }
+
+    #[test]
+    fn free_functions_match_plans() {
+        let mut state = 0x1234_5678_9abc_def0;
+        for log in 0..12 {
+            let n = 1 << log;
+            let omega = Plan::new(n, MODULUS).unwrap().omega();
+            let original = random_elements(&mut state, n, MODULUS);
+            let mut radix_4 = original.clone();
+            let mut split_radix = original.clone();
+            let mut radix_2 = original.clone();
+            ntt::radix_4(&mut radix_4, omega, MODULUS).unwrap();
+            ntt::split_radix(&mut split_radix, omega, MODULUS).unwrap();
+            ntt::cooley_tukey(&mut radix_2, omega, MODULUS).unwrap();
+            assert_eq!(radix_2, radix_4);
+            assert_eq!(radix_2, split_radix);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ntt_errors {
+    use nordint::ntt::{self, Kernel, Plan};
+    use nordint::{NttError, RootOfUnityError};
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(Err(NttError::EmptyInput), ntt::forward(&mut [], 1, 17));
+        assert_eq!(Err(NttError::EmptyInput), ntt::cooley_tukey(&mut [], 1, 17));
+        assert_eq!(Err(NttError::EmptyInput), ntt::fft_convolution(&[], &[1]));
+    }
+
+    #[test]
+    fn wrong_lengths() {
+        assert_eq!(
+            Err(NttError::LengthNotPowerOfTwo(3)),
+            ntt::cooley_tukey(&mut [1, 2, 3], 7, 19)
+        );
+        assert_eq!(
+            Err(NttError::UnsupportedLength(7)),
+            ntt::mixed_radix(&mut [1, 2, 3, 4, 5, 6, 7], 2, 29)
+        );
+        assert_eq!(
+            Err(NttError::LengthNotPowerOfTwo(6)),
+            ntt::split_radix(&mut [1, 2, 3, 4, 5, 6], 8, 19)
+        );
+        assert_eq!(
+            Err(NttError::LengthNotPowerOfTwo(12)),
+            Plan::new(12, 13).unwrap().with_kernel(Kernel::Radix4)
+        );
+    }
+
+    #[test]
+    fn plan_length_mismatch() {
+        let plan = Plan::new(4, 17).unwrap();
+        assert_eq!(
+            Err(NttError::LengthMismatch { expected: 4, found: 2 }),
+            plan.forward(&mut [1, 2])
+        );
+        assert_eq!(
+            Err(NttError::LengthMismatch { expected: 4, found: 8 }),
+            plan.inverse(&mut [0; 8])
+        );
+    }
+
+    #[test]
+    fn modulus_overflow() {
+        assert_eq!(
+            Err(NttError::ModulusOverflow),
+            ntt::forward(&mut [1, 2], 4_000_000_006, 4_000_000_007)
+        );
+    }
+
+    #[test]
+    fn bluestein_needs_power_of_two_root() {
+        // 7 is a primitive 3rd root of unity mod 19, but 8 does not divide 18.
+        assert_eq!(
+            Err(NttError::NoPrimitiveRoot(RootOfUnityError::OrderDoesNotDivide {
+                order: 8,
+                modulus: 19
+            })),
+            ntt::bluestein(&mut [1, 2, 3], 7, 19)
+        );
+    }
+}
+
+#[cfg(test)]
+mod ntt_convolution {
+    use super::random_elements;
+    use nordint::{ntt, NttError};
+
+    fn naive_convolution(lhs: &[i64], rhs: &[i64]) -> Vec<i64> {
+        let mut result = vec![0; lhs.len() + rhs.len() - 1];
+        for (i, lx) in lhs.iter().enumerate() {
+            for (j, rx) in rhs.iter().enumerate() {
+                result[i + j] += lx * rx;
+            }
+        }
+        while result.ends_with(&[0]) {
+            result.pop();
+        }
+        result
+    }
+
+    #[test]
+    fn matches_naive() {
+        let mut state = 0x1357_9bdf_0246_8ace;
+        for lhs_len in 1..40 {
+            for &rhs_len in &[1, 2, 3, 17, 40] {
+                let lhs = random_elements(&mut state, lhs_len, 100);
+                let rhs = random_elements(&mut state, rhs_len, 100);
+                assert_eq!(Ok(naive_convolution(&lhs, &rhs)), ntt::fft_convolution(&lhs, &rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn multi_modular_matches_naive() {
+        let mut state = 0x0f0f_f0f0_1234_4321;
+        for &(len, max) in &[(1, 2), (5, 1_000_000_000), (33, 500_000_000), (100, 1 << 28)] {
+            let lhs = random_elements(&mut state, len, max);
+            let rhs = random_elements(&mut state, len + 3, max);
+            assert_eq!(Ok(naive_convolution(&lhs, &rhs)), ntt::multi_modular_convolution(&lhs, &rhs));
+        }
+    }
+
+    #[test]
+    fn multi_modular_matches_single_modulus() {
+        // Long enough to cross the threshold where the `parallel` feature spawns threads.
+        let mut state = 0x3c3c_c3c3_5a5a_a5a5;
+        let lhs = random_elements(&mut state, 70_000, 100);
+        let rhs = random_elements(&mut state, 50_000, 100);
+        assert_eq!(ntt::fft_convolution(&lhs, &rhs), ntt::multi_modular_convolution(&lhs, &rhs));
+    }
+
+    #[test]
+    fn large_elements_fall_back_to_multi_modular() {
+        // Squaring elements of 10^9 and more overflows the bound on a single modulus.
+        let mut state = 0x7e57_0b1e_c0de_f00d;
+        for &(len, max) in &[(8, 1_000_000_001), (3, 1_700_000_000)] {
+            let mut lhs = random_elements(&mut state, len, max);
+            lhs[0] = 1_000_000_000;
+            let rhs = random_elements(&mut state, len + 1, max);
+            let expected = ntt::multi_modular_convolution(&lhs, &rhs);
+            assert!(expected.is_ok());
+            assert_eq!(expected, ntt::fft_convolution(&lhs, &rhs));
+        }
+        let billions = [1_000_000_000; 8];
+        let expected = ntt::multi_modular_convolution(&billions, &billions);
+        assert!(expected.is_ok());
+        assert_eq!(expected, ntt::fft_convolution(&billions, &billions));
+    }
+
+    #[test]
+    fn multi_modular_matches_a_known_convolution() {
+        // Coefficients up to 10^12 * 2^16 need three primes, and the transforms
+        // are long enough for the `parallel` feature to run them on separate threads.
+        let (len, value) = (1 << 16, 1_000_000);
+        let expected = (0..2 * len - 1)
+            .map(|k| value * value * if k < len { k + 1 } else { 2 * len - 1 - k })
+            .collect::<Vec<i64>>();
+        let ones = vec![value; len as usize];
+        assert_eq!(Ok(expected), ntt::multi_modular_convolution(&ones, &ones));
+    }
+
+    #[test]
+    fn multi_modular_errors() {
+        assert_eq!(Err(NttError::EmptyInput), ntt::multi_modular_convolution(&[], &[1]));
+        assert_eq!(
+            Err(NttError::ModulusOverflow),
+            ntt::multi_modular_convolution(&[1 << 32, 1], &[1 << 32, 1])
+        );
+        assert_eq!(
+            Err(NttError::NoModulusFound),
+            ntt::multi_modular_convolution(&vec![1; 1 << 22], &vec![1; (1 << 22) + 2])
+        );
+    }
+
+    #[test]
+    fn zero_is_empty() {
+        assert_eq!(Ok(vec![]), ntt::fft_convolution(&[0, 0], &[5]));
+    }
+
+    #[test]
+    fn smooth_lengths() {
+        let expected = [1, 1, 2, 3, 4, 5, 6, 8, 8, 9, 10, 12, 12, 15, 15, 15, 16, 18];
+        for (n, length) in expected.iter().enumerate() {
+            assert_eq!(*length, ntt::smooth_length(n));
+        }
+        assert_eq!(1_000, ntt::smooth_length(1_000));
+        assert_eq!(1_024, ntt::smooth_length(1_001));
+        assert_eq!(1_000_000, ntt::smooth_length(999_999));
+    }
+}
+
+#[cfg(test)]
+mod ntt_ring_convolution {
+    use super::random_elements;
+    use nordint::{ntt, NttError};
+
+    const MODULUS: i64 = 998_244_353;
+
+    /// Multiplies in Z_p[x] / (x^n - sign), so `sign` is 1 for cyclic and -1 for negacyclic.
+    fn naive_ring_product(lhs: &[i64], rhs: &[i64], sign: i64, modulus: i64) -> Vec<i64> {
+        let n = lhs.len();
+        let mut result = vec![0; n];
+        for (i, lx) in lhs.iter().enumerate() {
+            for (j, rx) in rhs.iter().enumerate() {
+                let term = lx * rx % modulus;
+                let term = if i + j < n { term } else { (sign * term).rem_euclid(modulus) };
+                result[(i + j) % n] = (result[(i + j) % n] + term) % modulus;
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn cyclic_matches_naive() {
+        let mut state = 0x5555_aaaa_3333_cccc;
+        for &n in &[1, 2, 4, 7, 8, 14, 17, 64, 119, 256] {
+            let lhs = random_elements(&mut state, n, MODULUS);
+            let rhs = random_elements(&mut state, n, MODULUS);
+            assert_eq!(
+                Ok(naive_ring_product(&lhs, &rhs, 1, MODULUS)),
+                ntt::cyclic_convolution(&lhs, &rhs, MODULUS),
+                "n = {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn negacyclic_matches_naive() {
+        let mut state = 0x7777_bbbb_1111_eeee;
+        for &n in &[1, 2, 4, 7, 14, 16, 17, 56, 128, 512] {
+            let lhs = random_elements(&mut state, n, MODULUS);
+            let rhs = random_elements(&mut state, n, MODULUS);
+            assert_eq!(
+                Ok(naive_ring_product(&lhs, &rhs, -1, MODULUS)),
+                ntt::negacyclic_convolution(&lhs, &rhs, MODULUS),
+                "n = {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn wrapping_monomials() {
+        // x^3 * x^2 = x^5 = x (cyclic) or -x (negacyclic) in degree 4.
+        let lhs = [0, 0, 0, 1];
+        let rhs = [0, 0, 1, 0];
+        assert_eq!(Ok(vec![0, 1, 0, 0]), ntt::cyclic_convolution(&lhs, &rhs, 17));
+        assert_eq!(Ok(vec![0, 16, 0, 0]), ntt::negacyclic_convolution(&lhs, &rhs, 17));
+    }
+
+    #[test]
+    fn negative_inputs_are_reduced() {
+        assert_eq!(
+            ntt::negacyclic_convolution(&[16, 1], &[3, 15], 17),
+            ntt::negacyclic_convolution(&[-1, 1], &[3, -2], 17)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Err(NttError::LengthMismatch { expected: 2, found: 3 }),
+            ntt::cyclic_convolution(&[1, 2], &[1, 2, 3], 17)
+        );
+        assert_eq!(Err(NttError::EmptyInput), ntt::negacyclic_convolution(&[], &[], 17));
+        // 16 divides 17 - 1, but 32 does not.
+        assert!(ntt::cyclic_convolution(&[1; 16], &[1; 16], 17).is_ok());
+        assert!(ntt::negacyclic_convolution(&[1; 16], &[1; 16], 17).is_err());
+    }
+}
+
+#[cfg(test)]
+mod ntt_montgomery {
+    use super::random_elements;
+    use nordint::ntt::{self, Kernel, Montgomery, Plan};
+
+    const MODULI: [u32; 4] = [3, 17, 998_244_353, 2_147_483_647];
+
+    fn random_residues(state: &mut u64, len: usize, modulus: u32) -> Vec<u32> {
+        random_elements(state, len, i64::from(modulus))
+            .into_iter()
+            .map(|element| element as u32)
+            .collect()
+    }
+
+    #[test]
+    fn rejects_unsupported_moduli() {
+        assert_eq!(None, Montgomery::new(0));
+        assert_eq!(None, Montgomery::new(998_244_352));
+        assert_eq!(None, Montgomery::new(1 << 31));
+        assert_eq!(None, Montgomery::new(3_037_000_493));
+        assert_eq!(Some(17), Montgomery::new(17).map(|montgomery| montgomery.modulus()));
+    }
+
+    #[test]
+    fn arithmetic_matches_remainder() {
+        let mut state = 0x6a09_e667_f3bc_c908;
+        for &modulus in MODULI.iter() {
+            let montgomery = Montgomery::new(modulus).unwrap();
+            let lhs = random_residues(&mut state, 500, modulus);
+            let rhs = random_residues(&mut state, 500, modulus);
+            for (lx, rx) in lhs.iter().zip(rhs.iter()) {
+                let (lm, rm) = (montgomery.to_montgomery(*lx), montgomery.to_montgomery(*rx));
+                assert_eq!(*lx, montgomery.from_montgomery(lm));
+                let expected = (u64::from(*lx) * u64::from(*rx) % u64::from(modulus)) as u32;
+                assert_eq!(expected, montgomery.from_montgomery(montgomery.mul(lm, rm)));
+            }
+        }
+    }
+
+    #[test]
+    fn pointwise_matches_scalar() {
+        let mut state = 0xbb67_ae85_84ca_a73b;
+        for &modulus in MODULI.iter() {
+            let montgomery = Montgomery::new(modulus).unwrap();
+            for len in (0..40).chain(vec![1_000, 4_099]) {
+                let lhs = random_residues(&mut state, len, modulus);
+                let rhs = random_residues(&mut state, len, modulus);
+                let (mut vectorized, mut scalar) = (lhs.clone(), lhs);
+                montgomery.pointwise(&mut vectorized, &rhs);
+                montgomery.pointwise_scalar(&mut scalar, &rhs);
+                assert_eq!(scalar, vectorized, "modulus = {}, len = {}", modulus, len);
+            }
+        }
+    }
+
+    #[test]
+    fn butterflies_match_scalar() {
+        let mut state = 0x3c6e_f372_fe94_f82b;
+        for &modulus in MODULI.iter() {
+            let montgomery = Montgomery::new(modulus).unwrap();
+            for len in (0..40).chain(vec![1_000, 4_099]) {
+                let lower = random_residues(&mut state, len, modulus);
+                let upper = random_residues(&mut state, len, modulus);
+                let twiddles = random_residues(&mut state, len, modulus);
+                let (mut vectorized_lower, mut vectorized_upper) = (lower.clone(), upper.clone());
+                let (mut scalar_lower, mut scalar_upper) = (lower, upper);
+                montgomery.butterflies(&mut vectorized_lower, &mut vectorized_upper, &twiddles);
+                montgomery.butterflies_scalar(&mut scalar_lower, &mut scalar_upper, &twiddles);
+                assert_eq!(scalar_lower, vectorized_lower, "modulus = {}, len = {}", modulus, len);
+                assert_eq!(scalar_upper, vectorized_upper, "modulus = {}, len = {}", modulus, len);
+            }
+        }
+    }
+
+    #[test]
+    fn power_of_two_kernels_match_radix_4() {
+        // Radix 2 and split radix take the Montgomery path under the `simd` feature,
+        // radix 4 never does. 17 * 2^27 + 1 is above 2^31, so it takes the scalar path either way.
+        let mut state = 0xa54f_f53a_5f1d_36f1;
+        for &modulus in &[998_244_353, 2_013_265_921, 2_281_701_377] {
+            for log in 0..14 {
+                let n = 1 << log;
+                let omega = nordint::root_of_unity(n as u64, modulus as u64).unwrap() as i64;
+                let original = random_elements(&mut state, n, modulus);
+                let (mut radix_2, mut split_radix, mut radix_4) = (original.clone(), original.clone(), original);
+                ntt::cooley_tukey(&mut radix_2, omega, modulus).unwrap();
+                ntt::split_radix(&mut split_radix, omega, modulus).unwrap();
+                ntt::radix_4(&mut radix_4, omega, modulus).unwrap();
+                assert_eq!(radix_4, radix_2, "modulus = {}, n = {}", modulus, n);
+                assert_eq!(radix_4, split_radix, "modulus = {}, n = {}", modulus, n);
+            }
+        }
+    }
+
+    #[test]
+    fn fft_convolution_matches_scalar() {
+        // Under the `simd` feature, `fft_convolution` runs its transforms and pointwise
+        // products over Montgomery residues. The reference only uses radix 4 and `%`.
+        let modulus = 998_244_353;
+        let mut state = 0x510e_527f_ade6_82d1;
+        for &(lhs_len, rhs_len) in &[(1, 1), (3, 2), (17, 40), (500, 524), (1_000, 3_000), (4_096, 4_097)] {
+            let lhs = random_elements(&mut state, lhs_len, 100);
+            let rhs = random_elements(&mut state, rhs_len, 100);
+            let plan = Plan::new((lhs_len + rhs_len - 1).next_power_of_two(), modulus)
+                .unwrap()
+                .with_kernel(Kernel::Radix4)
+                .unwrap();
+            let (mut lhs_scalar, mut rhs_scalar) = (lhs.clone(), rhs.clone());
+            lhs_scalar.resize(plan.size(), 0);
+            rhs_scalar.resize(plan.size(), 0);
+            plan.forward(&mut lhs_scalar).unwrap();
+            plan.forward(&mut rhs_scalar).unwrap();
+            let mut expected = lhs_scalar
+                .iter()
+                .zip(rhs_scalar.iter())
+                .map(|(lx, rx)| lx * rx % modulus)
+                .collect::<Vec<i64>>();
+            plan.inverse(&mut expected).unwrap();
+            while expected.ends_with(&[0]) {
+                expected.pop();
+            }
+            assert_eq!(Ok(expected), ntt::fft_convolution(&lhs, &rhs), "lengths {} and {}", lhs_len, rhs_len);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "butterfly operands differ in length")]
+    fn butterflies_check_lengths() {
+        Montgomery::new(17).unwrap().butterflies(&mut [1, 2], &mut [3], &[4, 5]);
+    }
+}
@@ -0,0 +1,223 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+extern crate num_bigint;
+
+mod common;
+
+#[cfg(test)]
+mod ops_biguint {
+    use common::random_digits;
+    use nordint::BigUint;
+    use num_bigint::BigUint as CrateBigUint;
+    use std::str::FromStr;
+
+    fn crate_biguint(number: &BigUint) -> CrateBigUint {
+        CrateBigUint::from_str(&number.to_string()).unwrap()
+    }
+
+    /// Pairs of random operands with the left-hand side the larger of the two.
+    fn operands() -> Vec<(BigUint, BigUint)> {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        [(1, 1), (5, 3), (40, 40), (41, 17), (300, 2), (1_000, 350)]
+            .iter()
+            .map(|&(lhs_len, rhs_len)| {
+                let lhs = BigUint::new(&random_digits(&mut state, lhs_len));
+                let rhs = BigUint::new(&random_digits(&mut state, rhs_len));
+                if lhs < rhs {
+                    (rhs, lhs)
+                } else {
+                    (lhs, rhs)
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn add_every_combination() {
+        for (lhs, rhs) in operands() {
+            let expected = (crate_biguint(&lhs) + crate_biguint(&rhs)).to_string();
+            assert_eq!(expected, (&lhs + &rhs).to_string());
+            assert_eq!(expected, (&rhs + &lhs).to_string());
+            assert_eq!(expected, (lhs.clone() + &rhs).to_string());
+            assert_eq!(expected, (&lhs + rhs.clone()).to_string());
+            assert_eq!(expected, (lhs.clone() + rhs.clone()).to_string());
+            assert_eq!(expected, (rhs.clone() + lhs.clone()).to_string());
+            let mut sum = lhs.clone();
+            sum += rhs.clone();
+            assert_eq!(expected, sum.to_string());
+            let mut sum = rhs.clone();
+            sum += lhs.clone();
+            assert_eq!(expected, sum.to_string());
+        }
+    }
+
+    #[test]
+    fn sub_every_combination() {
+        for (lhs, rhs) in operands() {
+            let expected = (crate_biguint(&lhs) - crate_biguint(&rhs)).to_string();
+            assert_eq!(expected, (&lhs - &rhs).to_string());
+            assert_eq!(expected, (lhs.clone() - &rhs).to_string());
+            assert_eq!(expected, (&lhs - rhs.clone()).to_string());
+            assert_eq!(expected, (lhs.clone() - rhs.clone()).to_string());
+            let mut difference = lhs.clone();
+            difference -= &rhs;
+            assert_eq!(expected, difference.to_string());
+            let mut difference = lhs.clone();
+            difference -= rhs.clone();
+            assert_eq!(expected, difference.to_string());
+            assert_eq!(BigUint::zero(), &lhs - lhs.clone());
+        }
+    }
+
+    #[test]
+    fn mul_every_combination() {
+        for (lhs, rhs) in operands() {
+            let expected = (crate_biguint(&lhs) * crate_biguint(&rhs)).to_string();
+            assert_eq!(expected, (lhs.clone() * &rhs).to_string());
+            assert_eq!(expected, (&lhs * rhs.clone()).to_string());
+            assert_eq!(expected, (lhs.clone() * rhs.clone()).to_string());
+            let mut product = lhs.clone();
+            product *= &rhs;
+            assert_eq!(expected, product.to_string());
+            let mut product = lhs.clone();
+            product *= rhs.clone();
+            assert_eq!(expected, product.to_string());
+        }
+    }
+
+    #[test]
+    fn div_rem_every_combination() {
+        for (lhs, rhs) in operands() {
+            let quotient = (crate_biguint(&lhs) / crate_biguint(&rhs)).to_string();
+            let remainder = (crate_biguint(&lhs) % crate_biguint(&rhs)).to_string();
+            assert_eq!(quotient, (&lhs / &rhs).to_string());
+            assert_eq!(quotient, (lhs.clone() / &rhs).to_string());
+            assert_eq!(quotient, (&lhs / rhs.clone()).to_string());
+            assert_eq!(quotient, (lhs.clone() / rhs.clone()).to_string());
+            assert_eq!(remainder, (&lhs % &rhs).to_string());
+            assert_eq!(remainder, (lhs.clone() % &rhs).to_string());
+            assert_eq!(remainder, (&lhs % rhs.clone()).to_string());
+            assert_eq!(remainder, (lhs.clone() % rhs.clone()).to_string());
+            let mut number = lhs.clone();
+            number /= &rhs;
+            assert_eq!(quotient, number.to_string());
+            let mut number = lhs.clone();
+            number %= rhs.clone();
+            assert_eq!(remainder, number.to_string());
+        }
+    }
+
+    #[test]
+    fn sub_trims_leading_zeros() {
+        let lhs = BigUint::new("1,000,000,000,000,000,000,000");
+        let rhs = BigUint::new("999,999,999,999,999,999,999");
+        assert_eq!(BigUint::one(), &lhs - rhs.clone());
+        assert_eq!(BigUint::one(), lhs - rhs);
+    }
+
+    #[test]
+    fn empty_lhs_stays_empty() {
+        assert_eq!(BigUint::empty(), BigUint::empty() + BigUint::new("5"));
+        assert_eq!(BigUint::empty(), &BigUint::empty() + BigUint::new("5"));
+        assert_eq!(BigUint::new("5"), BigUint::new("5") + BigUint::empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract a larger BigUint from a smaller one")]
+    fn sub_underflow() {
+        let _ = &BigUint::new("5") - BigUint::new("6");
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide a BigUint by zero")]
+    fn div_by_zero() {
+        let _ = BigUint::new("5") / BigUint::zero();
+    }
+}
+
+#[cfg(test)]
+mod ops_primitives {
+    use nordint::BigUint;
+
+    #[test]
+    fn every_width_on_either_side() {
+        let number = BigUint::new("123,456,789,012,345,678,901");
+        assert_eq!(BigUint::new("123,456,789,012,345,679,001"), &number + 100_u8);
+        assert_eq!(BigUint::new("123,456,789,012,345,679,001"), 100_u8 + &number);
+        assert_eq!(BigUint::new("123,456,789,012,345,613,366"), number.clone() - 65_535_u16);
+        assert_eq!(BigUint::new("17"), 200_u16 - BigUint::new("183"));
+        assert_eq!(BigUint::new("370,370,367,037,037,036,703"), &number * 3_u32);
+        assert_eq!(BigUint::new("370,370,367,037,037,036,703"), 3_u32 * number.clone());
+        assert_eq!(BigUint::new("12,345,678,901,234"), &number / 10_000_000_u64);
+        assert_eq!(BigUint::new("5,678,901"), &number % 10_000_000_u64);
+        assert_eq!(BigUint::new("8"), 1_000_u64 / BigUint::new("123"));
+        assert_eq!(BigUint::new("16"), 1_000_u128 % &BigUint::new("123"));
+        assert_eq!(BigUint::new("123,456,789,012,345,678,901,000,000"), number.clone() * 1_000_000_usize);
+        assert_eq!(number, BigUint::from(123_456_789_012_345_678_901_u128) + 0_usize);
+    }
+
+    #[test]
+    fn unsuffixed_literals() {
+        // An unsuffixed literal fits every width, so it falls back to i32.
+        let number = BigUint::new("123,456,789,012,345,678,901");
+        assert_eq!(BigUint::new("370,370,367,037,037,036,703"), number.clone() * 3);
+        assert_eq!(BigUint::new("370,370,367,037,037,036,703"), 3 * number.clone());
+        assert_eq!(BigUint::new("123,456,789,012,345,678,902"), number.clone() + 1);
+        assert_eq!(BigUint::new("123,456,789,012,345,678,900"), &number - 1);
+        assert_eq!(BigUint::new("12,345,678,901,234,567,890"), &number / 10);
+        assert_eq!(BigUint::new("1"), &number % 10);
+        assert_eq!(BigUint::new("2"), 1_000 % &BigUint::new("998"));
+    }
+
+    #[test]
+    fn large_primitives() {
+        let number = BigUint::new("123,456,789,012,345,678,901");
+        let large = u128::MAX;
+        let expected = BigUint::new("42,010,168,377,579,896,403,460,218,461,160,783,255,736,856,600,282,500,010,955");
+        assert_eq!(expected, &number * large);
+        assert_eq!(expected, large * &number);
+        assert_eq!(number, &expected / large);
+        assert_eq!(BigUint::zero(), &expected % large);
+        let divisor = u64::MAX;
+        assert_eq!(BigUint::new("6"), &number / divisor);
+        assert_eq!(BigUint::new("12,776,324,570,088,369,211"), &number % divisor);
+    }
+
+    #[test]
+    fn assigning_forms() {
+        // Unsuffixed literals resolve to the single u32 impl of each assigning operator.
+        let mut number = BigUint::new("1,000");
+        number += 24;
+        number -= 1;
+        number *= 7;
+        number /= 3;
+        assert_eq!(BigUint::new("2,387"), number);
+        number %= 100;
+        assert_eq!(BigUint::new("87"), number);
+        number *= u32::MAX;
+        assert_eq!(BigUint::from(u32::MAX) * 87_u8, number);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide a BigUint by zero")]
+    fn div_by_zero() {
+        let _ = BigUint::new("5") / 0_u8;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract a larger BigUint from a smaller one")]
+    fn sub_underflow() {
+        let _ = 5_u64 - BigUint::new("6");
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to combine a BigUint with a negative integer")]
+    fn negative_literal() {
+        let _ = BigUint::new("5") + -1;
+    }
+}
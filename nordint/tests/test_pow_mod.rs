@@ -0,0 +1,169 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+extern crate num_bigint;
+
+mod common;
+
+/// Calculates `base^exponent % modulus` one multiplication at a time.
+#[cfg(test)]
+fn naive_pow_mod(base: u128, exponent: u32, modulus: u128) -> u128 {
+    (0..exponent).fold(1 % modulus, |result, _| result * (base % modulus) % modulus)
+}
+
+#[cfg(test)]
+mod pow_mod_primitives {
+    use super::naive_pow_mod;
+    use nordint::PowMod;
+
+    #[test]
+    fn u32_matches_naive() {
+        for &modulus in &[1_u32, 2, 7, 10, 97, 1_000, 65_537] {
+            for base in 0..50_u32 {
+                for exponent in 0..40_u32 {
+                    let expected = naive_pow_mod(u128::from(base), exponent, u128::from(modulus));
+                    assert_eq!(expected as u32, base.pow_mod(exponent, modulus));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn u64_fermat() {
+        for &prime in &[998_244_353_u64, 1_000_000_007, 18_446_744_073_709_551_557] {
+            for &base in &[2_u64, 3, 12_345, 987_654_321] {
+                assert_eq!(1, base.pow_mod(prime - 1, prime));
+            }
+        }
+    }
+
+    #[test]
+    fn u128_matches_naive() {
+        for &modulus in &[1_u128, 1_000, 4_294_967_311, 1 << 64] {
+            for &base in &[0_u128, 3, 1_234_567_890_123, u128::from(u64::MAX)] {
+                for exponent in 0..70_u32 {
+                    // Every modulus is at most 2^64, so the naive products fit in a u128.
+                    let expected = naive_pow_mod(base, exponent, modulus);
+                    assert_eq!(expected, base.pow_mod(u128::from(exponent), modulus));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn u128_large_modulus() {
+        // 2^127 - 1 is prime, so Fermat's little theorem holds for every base.
+        let prime = (1_u128 << 127) - 1;
+        for &base in &[2_u128, 3, u128::MAX - 5] {
+            assert_eq!(1, base.pow_mod(prime - 1, prime));
+        }
+        let modulus = u128::MAX;
+        assert_eq!(1, (modulus - 1).pow_mod(2, modulus));
+        assert_eq!(modulus - 1, (modulus - 1).pow_mod(3, modulus));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn zero_modulus() {
+        let _ = 3_u64.pow_mod(2, 0);
+    }
+}
+
+#[cfg(test)]
+mod pow_mod_biguint {
+    use common::random_digits;
+    use nordint::{BigUint, PowMod};
+    use num_bigint::BigUint as CrateBigUint;
+    use std::str::FromStr;
+
+    fn check(base: &str, exponent: &str, modulus: &str) {
+        let expected = CrateBigUint::from_str(base).unwrap().modpow(
+            &CrateBigUint::from_str(exponent).unwrap(),
+            &CrateBigUint::from_str(modulus).unwrap(),
+        );
+        let actual = BigUint::new(base).modpow(&BigUint::new(exponent), &BigUint::new(modulus));
+        assert_eq!(expected.to_string(), actual.to_string(), "{}^{} % {}", base, exponent, modulus);
+    }
+
+    #[test]
+    fn small_values() {
+        for modulus in 1..120_u32 {
+            for base in 0..30_u32 {
+                for exponent in 0..20_u32 {
+                    check(&base.to_string(), &exponent.to_string(), &modulus.to_string());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn coprime_to_ten() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for &(base_len, exponent_len, modulus_len) in &[(5, 3, 7), (40, 30, 21), (100, 100, 99), (300, 50, 301)] {
+            let base = random_digits(&mut state, base_len);
+            let exponent = random_digits(&mut state, exponent_len);
+            for &last in &["1", "3", "7", "9"] {
+                let modulus = random_digits(&mut state, modulus_len) + last;
+                check(&base, &exponent, &modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn even_and_multiples_of_five() {
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        for &(base_len, exponent_len, modulus_len) in &[(5, 3, 7), (40, 30, 21), (100, 100, 99), (300, 50, 301)] {
+            let base = random_digits(&mut state, base_len);
+            let exponent = random_digits(&mut state, exponent_len);
+            for &last in &["0", "2", "4", "5", "6", "8"] {
+                let modulus = random_digits(&mut state, modulus_len) + last;
+                check(&base, &exponent, &modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn powers_of_ten_and_two() {
+        check("123456789", "1000", "1000000000000");
+        check("3", "12345", "1048576");
+        check("99999999999999999999", "99999999999999999999", "100000000000000000000");
+    }
+
+    #[test]
+    fn fermat() {
+        // 2^521 - 1 is a Mersenne prime.
+        let prime = BigUint::new("6864797660130609714981900799081393217269435300143305409394463459185543183397656052122559640661454554977296311391480858037121987999716643812574028291115057151");
+        let exponent = &prime - &BigUint::one();
+        for &base in &[2_u32, 3, 65_537] {
+            assert_eq!(BigUint::one(), BigUint::from(base).modpow(&exponent, &prime));
+        }
+    }
+
+    #[test]
+    fn trait_matches_method() {
+        let base = BigUint::new("123456789123456789");
+        let exponent = BigUint::new("987654321");
+        let modulus = BigUint::new("1000000000000000000000");
+        assert_eq!(base.modpow(&exponent, &modulus), (&base).pow_mod(&exponent, &modulus));
+        assert_eq!(base.modpow(&exponent, &modulus), base.clone().pow_mod(exponent, modulus));
+    }
+
+    #[test]
+    fn zero_exponent_and_unit_modulus() {
+        let base = BigUint::new("123456789");
+        assert_eq!(BigUint::one(), base.modpow(&BigUint::zero(), &BigUint::new("1009")));
+        assert_eq!(BigUint::one(), base.modpow(&BigUint::zero(), &BigUint::new("1000")));
+        assert_eq!(BigUint::zero(), base.modpow(&BigUint::new("5"), &BigUint::one()));
+        assert_eq!(BigUint::zero(), BigUint::zero().modpow(&BigUint::new("5"), &BigUint::new("1009")));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn zero_modulus() {
+        let _ = BigUint::new("3").modpow(&BigUint::new("2"), &BigUint::zero());
+    }
+}
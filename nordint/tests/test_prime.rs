@@ -0,0 +1,108 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+#[cfg(test)]
+mod prime_is_prime {
+    use nordint::is_prime;
+
+    /// Reference sieve used to check every small number exhaustively.
+    fn sieve(limit: usize) -> Vec<bool> {
+        let mut primes = vec![true; limit];
+        primes[0] = false;
+        primes[1] = false;
+        for i in 2..limit {
+            if primes[i] {
+                for multiple in (i * i..limit).step_by(i) {
+                    primes[multiple] = false;
+                }
+            }
+        }
+        primes
+    }
+
+    #[test]
+    fn zero_and_one() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+    }
+
+    #[test]
+    fn agrees_with_sieve() {
+        let primes = sieve(100_000);
+        for (number, expected) in primes.iter().enumerate() {
+            assert_eq!(*expected, is_prime(number as u64), "{}", number);
+        }
+        assert_eq!(9592, primes.iter().filter(|prime| **prime).count());
+    }
+
+    #[test]
+    fn carmichael_numbers() {
+        for number in &[561, 1105, 1729, 2465, 2821, 6601, 8911, 41041, 825265] {
+            assert!(!is_prime(*number));
+        }
+    }
+
+    #[test]
+    fn strong_pseudoprimes() {
+        // Strong pseudoprimes to the first few prime bases.
+        assert!(!is_prime(2047));
+        assert!(!is_prime(1_373_653));
+        assert!(!is_prime(25_326_001));
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(2_152_302_898_747));
+        assert!(!is_prime(3_474_749_660_383));
+        assert!(!is_prime(341_550_071_728_321));
+        assert!(!is_prime(3_825_123_056_546_413_051));
+    }
+
+    #[test]
+    fn large_primes() {
+        assert!(is_prime(998_244_353));
+        assert!(is_prime(2_305_843_009_213_693_951)); // 2^61 - 1
+        assert!(is_prime(9_223_372_036_854_775_837));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(18_446_744_073_709_551_615));
+    }
+}
+
+#[cfg(test)]
+mod prime_next_prev {
+    use nordint::{next_prime, prev_prime};
+
+    #[test]
+    fn next_small() {
+        assert_eq!(Some(2), next_prime(0));
+        assert_eq!(Some(2), next_prime(1));
+        assert_eq!(Some(3), next_prime(2));
+        assert_eq!(Some(5), next_prime(3));
+        assert_eq!(Some(11), next_prime(7));
+        assert_eq!(Some(127), next_prime(113));
+    }
+
+    #[test]
+    fn next_overflows() {
+        assert_eq!(Some(9_223_372_036_854_775_837), next_prime(1 << 63));
+        assert_eq!(None, next_prime(18_446_744_073_709_551_557));
+        assert_eq!(None, next_prime(u64::MAX));
+    }
+
+    #[test]
+    fn prev_small() {
+        assert_eq!(None, prev_prime(0));
+        assert_eq!(None, prev_prime(2));
+        assert_eq!(Some(2), prev_prime(3));
+        assert_eq!(Some(3), prev_prime(4));
+        assert_eq!(Some(3), prev_prime(5));
+        assert_eq!(Some(113), prev_prime(127));
+    }
+
+    #[test]
+    fn prev_large() {
+        assert_eq!(Some(18_446_744_073_709_551_557), prev_prime(u64::MAX));
+    }
+}
@@ -0,0 +1,272 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+#[cfg(test)]
+fn ratio(text: &str) -> nordint::BigRational {
+    text.parse().unwrap()
+}
+
+#[cfg(test)]
+mod rational_arithmetic {
+    use super::ratio;
+    use nordint::{BigRational, BigUint};
+
+    /// Small fractions with every combination of signs.
+    fn fractions() -> Vec<(i64, i64)> {
+        let mut fractions = Vec::new();
+        for numerator in -6..=6 {
+            for denominator in 1..=6 {
+                fractions.push((numerator, denominator));
+            }
+        }
+        fractions
+    }
+
+    fn from_pair((numerator, denominator): (i64, i64)) -> BigRational {
+        &BigRational::from(numerator) / &BigRational::from(denominator)
+    }
+
+    fn gcd(lhs: i64, rhs: i64) -> i64 {
+        if 0 == rhs {
+            lhs.abs()
+        } else {
+            gcd(rhs, lhs % rhs)
+        }
+    }
+
+    /// Builds the expected fraction from an unreduced pair, reducing with `i64` arithmetic.
+    fn expected(numerator: i64, denominator: i64) -> BigRational {
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        let text = format!("{}/{}", numerator, denominator);
+        assert_eq!(text.trim_end_matches("/1"), ratio(&text).to_string());
+        ratio(&text)
+    }
+
+    #[test]
+    fn matches_i64() {
+        for &(a, b) in &fractions() {
+            for &(c, d) in &fractions() {
+                let (lhs, rhs) = (from_pair((a, b)), from_pair((c, d)));
+                assert_eq!(expected(a * d + c * b, b * d), &lhs + &rhs);
+                assert_eq!(expected(a * d - c * b, b * d), &lhs - &rhs);
+                assert_eq!(expected(a * c, b * d), &lhs * &rhs);
+                if 0 != c {
+                    assert_eq!(expected(a * d, b * c), &lhs / &rhs);
+                }
+                assert_eq!((a * d).cmp(&(c * b)), lhs.cmp(&rhs), "{}/{} vs {}/{}", a, b, c, d);
+            }
+        }
+    }
+
+    #[test]
+    fn owned_and_assigning_forms() {
+        let mut value = ratio("1/2");
+        value += ratio("1/3");
+        value -= &ratio("1/4");
+        value *= ratio("6");
+        value /= &ratio("-7/2");
+        assert_eq!(ratio("-1"), value);
+        assert_eq!(ratio("5/6"), ratio("1/2") + ratio("1/3"));
+        assert_eq!(ratio("1/6"), &ratio("1/2") - ratio("1/3"));
+        assert_eq!(ratio("1/6"), ratio("1/2") * &ratio("1/3"));
+        assert_eq!(ratio("3/2"), ratio("1/2") / ratio("1/3"));
+        assert_eq!(ratio("-1/2"), -ratio("1/2"));
+    }
+
+    #[test]
+    fn remainder_takes_sign_of_dividend() {
+        assert_eq!(ratio("1/6"), ratio("1/2") % ratio("1/3"));
+        assert_eq!(ratio("-1/6"), ratio("-1/2") % ratio("1/3"));
+        assert_eq!(ratio("1/6"), ratio("1/2") % ratio("-1/3"));
+        assert_eq!(ratio("0"), ratio("3") % ratio("3/4"));
+    }
+
+    #[test]
+    fn always_in_lowest_terms() {
+        let value = BigRational::new(BigUint::new("123,456,789,000"), BigUint::new("987,654,321,000"));
+        assert_eq!(&BigUint::new("13,717,421"), value.numerator());
+        assert_eq!(&BigUint::new("109,739,369"), value.denominator());
+        let zero = &ratio("-3/4") + &ratio("3/4");
+        assert!(!zero.is_negative());
+        assert_eq!(BigRational::zero(), zero);
+        assert_eq!(&BigUint::one(), zero.denominator());
+        assert_eq!(BigRational::zero(), -BigRational::zero());
+    }
+
+    #[test]
+    fn harmonic_number() {
+        let harmonic: BigRational = (1..=50_i64).fold(BigRational::zero(), |sum, n| sum + ratio(&format!("1/{}", n)));
+        assert_eq!(ratio("13943237577224054960759/3099044504245996706400"), harmonic);
+    }
+
+    #[test]
+    fn powers_and_reciprocals() {
+        assert_eq!(ratio("-27/8"), ratio("-3/2").pow(3));
+        assert_eq!(ratio("4/9"), ratio("-3/2").pow(-2));
+        assert_eq!(BigRational::one(), ratio("-3/2").pow(0));
+        assert_eq!(ratio("-2/3"), ratio("-3/2").recip());
+        assert_eq!(ratio("3/2"), ratio("-3/2").abs());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide a BigRational by zero")]
+    fn divide_by_zero() {
+        let _ = ratio("1/2") / BigRational::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator must be non-zero")]
+    fn zero_denominator() {
+        let _ = BigRational::new(BigUint::one(), BigUint::zero());
+    }
+}
+
+#[cfg(test)]
+mod rational_rounding {
+    use super::ratio;
+
+    #[test]
+    fn floor_ceil_round_trunc() {
+        let cases = [
+            ("7/2", "3", "4", "4", "3"),
+            ("-7/2", "-4", "-3", "-4", "-3"),
+            ("10/3", "3", "4", "3", "3"),
+            ("-10/3", "-4", "-3", "-3", "-3"),
+            ("11/3", "3", "4", "4", "3"),
+            ("-11/3", "-4", "-3", "-4", "-3"),
+            ("5", "5", "5", "5", "5"),
+            ("-5", "-5", "-5", "-5", "-5"),
+            ("1/3", "0", "1", "0", "0"),
+            ("-1/3", "-1", "0", "0", "0"),
+        ];
+        for &(value, floor, ceil, round, trunc) in &cases {
+            let value = ratio(value);
+            assert_eq!(ratio(floor), value.floor(), "floor({})", value);
+            assert_eq!(ratio(ceil), value.ceil(), "ceil({})", value);
+            assert_eq!(ratio(round), value.round(), "round({})", value);
+            assert_eq!(ratio(trunc), value.trunc(), "trunc({})", value);
+        }
+        assert!(!ratio("-1/3").ceil().is_negative());
+    }
+}
+
+#[cfg(test)]
+mod rational_conversions {
+    use super::ratio;
+    use nordint::{BigRational, BigUint};
+
+    /// Returns `numerator / 2^exponent`.
+    fn dyadic(numerator: BigUint, exponent: usize) -> BigRational {
+        BigRational::new(numerator, BigUint::one() << exponent)
+    }
+
+    #[test]
+    fn f64_round_trip() {
+        let values = [
+            0.0,
+            1.0,
+            -0.5,
+            0.1,
+            -1.0 / 3.0,
+            123_456.789_012_5,
+            1e300,
+            -2.5e-300,
+            5e-324,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+        ];
+        for &value in &values {
+            let rational = BigRational::from_f64(value).unwrap();
+            assert_eq!(value, rational.to_f64(), "{}", value);
+        }
+        assert_eq!(None, BigRational::from_f64(f64::INFINITY));
+        assert_eq!(Some(ratio("1/1024")), BigRational::from_f64(1.0 / 1024.0));
+    }
+
+    #[test]
+    fn to_f64_of_fractions() {
+        assert_eq!(1.0 / 3.0, ratio("1/3").to_f64());
+        assert_eq!(-22.0 / 7.0, ratio("-22/7").to_f64());
+        assert_eq!(0.1, ratio("0.1").to_f64());
+        let huge = ratio(&format!("1{}/3", "0".repeat(400)));
+        assert_eq!(f64::INFINITY, huge.to_f64());
+        let tiny = ratio(&format!("1/3{}", "0".repeat(400)));
+        assert_eq!(0.0, tiny.to_f64());
+    }
+
+    #[test]
+    fn to_f64_ties_to_even() {
+        // 2^53 + 1 and 2^53 + 3 are halfway between neighbouring f64s.
+        assert_eq!(9_007_199_254_740_992.0, ratio("9007199254740993").to_f64());
+        assert_eq!(9_007_199_254_740_996.0, ratio("9007199254740995").to_f64());
+        assert_eq!(-9_007_199_254_740_992.0, ratio("-9007199254740993").to_f64());
+        // Just above the tie, by less than a 64-bit quotient can see.
+        let above_tie = format!("9007199254740993{}1/1{}", "0".repeat(29), "0".repeat(30));
+        assert_eq!(9_007_199_254_740_994.0, ratio(&above_tie).to_f64());
+        let below_tie = format!("9007199254740992{}9/1{}", "9".repeat(29), "0".repeat(30));
+        assert_eq!(9_007_199_254_740_992.0, ratio(&below_tie).to_f64());
+        assert_eq!(9_007_199_254_740_994.0, ratio("27021597764222980/3").to_f64());
+    }
+
+    #[test]
+    fn to_f64_of_subnormals() {
+        let tiny = 5e-324;
+        assert_eq!(0.0, dyadic(BigUint::one(), 1_075).to_f64());
+        assert_eq!(2.0 * tiny, dyadic(BigUint::new("3"), 1_075).to_f64());
+        assert_eq!(tiny, dyadic((BigUint::one() << 60) + 1_u8, 1_135).to_f64());
+        assert_eq!(0.0, dyadic((BigUint::one() << 60) - 1_u8, 1_135).to_f64());
+        assert_eq!(f64::MIN_POSITIVE, dyadic((BigUint::one() << 53) - 1_u8, 1_075).to_f64());
+        // Division of f64s is correctly rounded, subnormal results included.
+        let values = [f64::MIN_POSITIVE, 1e-310, 3.5e-320, tiny, 1.0, 3.0, 7.0, 1e10];
+        for &lhs in &values {
+            for &rhs in &values {
+                let quotient = BigRational::from_f64(lhs).unwrap() / BigRational::from_f64(rhs).unwrap();
+                assert_eq!(lhs / rhs, quotient.to_f64(), "{} / {}", lhs, rhs);
+            }
+        }
+    }
+
+    #[test]
+    fn repeating_decimals() {
+        let cases = [
+            ("1/3", "0.(3)"),
+            ("-7/6", "-1.1(6)"),
+            ("1/7", "0.(142857)"),
+            ("22/7", "3.(142857)"),
+            ("1/8", "0.125"),
+            ("-5", "-5"),
+            ("0", "0"),
+            ("1/12", "0.08(3)"),
+            ("1/81", "0.(012345679)"),
+            ("3227/555", "5.8(144)"),
+        ];
+        for &(fraction, decimal) in &cases {
+            assert_eq!(decimal, ratio(fraction).to_decimal_string());
+            assert_eq!(ratio(fraction), ratio(decimal));
+        }
+    }
+
+    #[test]
+    fn parse_decimals() {
+        assert_eq!(ratio("314/100"), ratio("3.14"));
+        assert_eq!(ratio("-1/2"), ratio("-0.5"));
+        assert_eq!(ratio("1"), ratio("0.(9)"));
+        assert_eq!(ratio("1/2"), ratio("+0.4(9)"));
+        assert_eq!(ratio("1/4"), ratio("0.250"));
+        assert_eq!(ratio("1/2"), ratio(".5"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        for text in &["", "-", "1/", "/2", "1/-2", "1.2.3", "1.", "0.()", "0.(3", "0.3)", "a/2", "1e5", "--1"] {
+            assert!(text.parse::<BigRational>().is_err(), "{:?}", text);
+        }
+        assert_eq!("denominator is zero", "1/0".parse::<BigRational>().unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,155 @@
+// author:  Erik Nordin
+// created: 10/18/2026
+// updated: 10/18/2026
+// version: 0.1.0
+// contact: aeketn@gmail.com
+
+extern crate nordint;
+
+#[cfg(test)]
+mod roots_multiplicative_order {
+    use nordint::multiplicative_order;
+
+    /// Smallest k > 0 with value^k = 1, found by stepping through the powers.
+    fn naive_order(value: u64, modulus: u64) -> Option<u64> {
+        let mut power = value % modulus;
+        for k in 1..=modulus {
+            if 1 % modulus == power {
+                return Some(k);
+            }
+            power = power * value % modulus;
+        }
+        None
+    }
+
+    #[test]
+    fn agrees_with_naive() {
+        for modulus in 1..120 {
+            for value in 0..modulus {
+                assert_eq!(
+                    naive_order(value, modulus),
+                    multiplicative_order(value, modulus),
+                    "{} mod {}",
+                    value,
+                    modulus
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_modulus() {
+        assert_eq!(None, multiplicative_order(3, 0));
+    }
+
+    #[test]
+    fn large_prime() {
+        // 3 generates the multiplicative group of 998244353 = 119 * 2^23 + 1
+        assert_eq!(Some(998_244_352), multiplicative_order(3, 998_244_353));
+        assert_eq!(Some(2), multiplicative_order(998_244_352, 998_244_353));
+    }
+}
+
+#[cfg(test)]
+mod roots_primitive_root {
+    use nordint::{multiplicative_order, primitive_root, RootOfUnityError};
+
+    #[test]
+    fn small_primes() {
+        let expected = [(2, 1), (3, 2), (5, 2), (7, 3), (11, 2), (13, 2), (17, 3), (19, 2), (23, 5), (41, 6)];
+        for &(modulus, root) in expected.iter() {
+            assert_eq!(Ok(root), primitive_root(modulus));
+        }
+    }
+
+    #[test]
+    fn generates_the_group() {
+        for &modulus in &[7_340_033, 998_244_353, 2_305_843_009_213_693_951] {
+            let root = primitive_root(modulus).unwrap();
+            assert_eq!(Some(modulus - 1), multiplicative_order(root, modulus));
+        }
+    }
+
+    #[test]
+    fn composite_modulus() {
+        assert_eq!(Err(RootOfUnityError::ModulusNotPrime(0)), primitive_root(0));
+        assert_eq!(Err(RootOfUnityError::ModulusNotPrime(1)), primitive_root(1));
+        assert_eq!(Err(RootOfUnityError::ModulusNotPrime(561)), primitive_root(561));
+    }
+}
+
+#[cfg(test)]
+mod roots_root_of_unity {
+    use nordint::{factorize, next_prime, root_of_unity, RootOfUnityError};
+
+    /// Calculates `base^exponent % modulus` for checking the roots independently.
+    fn pow_mod(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+        let mul_mod = |lhs: u64, rhs: u64| (lhs as u128 * rhs as u128 % modulus as u128) as u64;
+        let (mut base, mut result) = (base % modulus, 1 % modulus);
+        while 0 < exponent {
+            if 1 == exponent & 1 {
+                result = mul_mod(result, base);
+            }
+            base = mul_mod(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Checks that omega has order exactly n:
+    /// omega^n = 1 and omega^(n / q) != 1 for every prime q dividing n.
+    fn assert_primitive(omega: u64, n: u64, modulus: u64) {
+        assert_eq!(1, pow_mod(omega, n, modulus), "{}^{} mod {}", omega, n, modulus);
+        for &(q, _) in factorize(n).factors() {
+            assert_ne!(1, pow_mod(omega, n / q, modulus), "{}^({} / {}) mod {}", omega, n, q, modulus);
+        }
+    }
+
+    #[test]
+    fn every_order_of_small_primes() {
+        for &modulus in &[2, 3, 5, 7, 13, 17, 97, 193, 257, 7681, 12289] {
+            for n in factorize(modulus - 1).divisors() {
+                assert_primitive(root_of_unity(n, modulus).unwrap(), n, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn power_of_two_orders_of_ntt_primes() {
+        for &(modulus, max_log) in &[(998_244_353, 23), (7_340_033, 20), (469_762_049, 26)] {
+            for log in 0..=max_log {
+                let n = 1 << log;
+                assert_primitive(root_of_unity(n, modulus).unwrap(), n, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn random_primes() {
+        // A fixed xorshift sequence keeps the test reproducible.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..200 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let modulus = next_prime(state >> 20).unwrap();
+            for n in factorize(modulus - 1).divisors().into_iter().take(16) {
+                assert_primitive(root_of_unity(n, modulus).unwrap(), n, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Err(RootOfUnityError::ZeroOrder), root_of_unity(0, 17));
+        assert_eq!(Err(RootOfUnityError::ModulusNotPrime(15)), root_of_unity(2, 15));
+        assert_eq!(
+            Err(RootOfUnityError::OrderDoesNotDivide { order: 32, modulus: 17 }),
+            root_of_unity(32, 17)
+        );
+        assert_eq!(
+            "no root of unity of order `3` exists modulo `17`: `3` does not divide `16`",
+            root_of_unity(3, 17).unwrap_err().to_string()
+        );
+    }
+}